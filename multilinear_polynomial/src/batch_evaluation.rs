@@ -0,0 +1,193 @@
+use crate::eq_polynomial::eq_evaluations;
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+use ark_ff::PrimeField;
+use std::collections::HashMap;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+impl<F: PrimeField> MultilinearPoly<F> {
+    /// Evaluates the polynomial at many points, sharing work between points with a common
+    /// prefix.
+    ///
+    /// The evaluations are viewed as a 2^h × 2^(n−h) matrix whose rows are indexed by the
+    /// leading h = n/2 variables, so W(r) = ⟨Σ_i eq(r_hi, i) · row_i, eq(r_lo, ·)⟩. The
+    /// row fold costs 2^n multiplications and depends only on r_hi, so it is done once per
+    /// distinct prefix; every further point with that prefix then costs only 2^(n−h). With
+    /// the `parallel` feature the prefix groups are spread across threads.
+    pub fn batch_evaluate(&self, points: &[Vec<F>]) -> Vec<F> {
+        if points.iter().any(|point| point.len() != self.num_of_vars) {
+            panic!("Invalid number of values");
+        }
+
+        let split = self.num_of_vars / 2;
+        let groups = group_by_prefix(points, split);
+
+        #[cfg(feature = "parallel")]
+        let group_evaluations: Vec<Vec<(usize, F)>> = groups
+            .par_iter()
+            .map(|(prefix, indices)| self.evaluate_group(prefix, indices, points, split))
+            .collect();
+
+        #[cfg(not(feature = "parallel"))]
+        let group_evaluations: Vec<Vec<(usize, F)>> = groups
+            .iter()
+            .map(|(prefix, indices)| self.evaluate_group(prefix, indices, points, split))
+            .collect();
+
+        let mut evaluations = vec![F::zero(); points.len()];
+        for (index, evaluation) in group_evaluations.into_iter().flatten() {
+            evaluations[index] = evaluation;
+        }
+
+        evaluations
+    }
+
+    /// Folds the rows against eq(prefix, ·) once, then finishes each point in the group with
+    /// a single inner product over the columns.
+    fn evaluate_group(
+        &self,
+        prefix: &[F],
+        indices: &[usize],
+        points: &[Vec<F>],
+        split: usize,
+    ) -> Vec<(usize, F)> {
+        let folded_row = self.fold_rows(prefix);
+
+        indices
+            .iter()
+            .map(|&index| {
+                let column_weights = eq_evaluations(&points[index][split..]);
+                (index, inner_product(&folded_row, &column_weights))
+            })
+            .collect()
+    }
+
+    /// Returns Σ_i eq(prefix, i) · row_i, i.e. the table with the leading variables fixed.
+    fn fold_rows(&self, prefix: &[F]) -> Vec<F> {
+        let row_weights = eq_evaluations(prefix);
+        let row_length = self.evaluation.len() / row_weights.len();
+        let mut folded_row = vec![F::zero(); row_length];
+
+        for (row, weight) in self.evaluation.chunks_exact(row_length).zip(row_weights) {
+            for (folded, value) in folded_row.iter_mut().zip(row) {
+                *folded += weight * value;
+            }
+        }
+
+        folded_row
+    }
+}
+
+/// Groups point indices by their first `split` coordinates, in order of first appearance.
+fn group_by_prefix<F: PrimeField>(points: &[Vec<F>], split: usize) -> Vec<(&[F], Vec<usize>)> {
+    let mut positions: HashMap<&[F], usize> = HashMap::new();
+    let mut groups: Vec<(&[F], Vec<usize>)> = Vec::new();
+
+    for (index, point) in points.iter().enumerate() {
+        let prefix = &point[..split];
+        let position = *positions.entry(prefix).or_insert_with(|| {
+            groups.push((prefix, Vec::new()));
+            groups.len() - 1
+        });
+        groups[position].1.push(index);
+    }
+
+    groups
+}
+
+/// Computes Σ a_i·b_i with four independent accumulators so consecutive multiplications
+/// do not wait on each other's additions.
+pub fn inner_product<F: PrimeField>(a: &[F], b: &[F]) -> F {
+    let mut accumulators = [F::zero(); 4];
+    let a_chunks = a.chunks_exact(4);
+    let b_chunks = b.chunks_exact(4);
+    let remainder: F = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .map(|(x, y)| *x * y)
+        .sum();
+
+    for (x, y) in a_chunks.zip(b_chunks) {
+        for lane in 0..4 {
+            accumulators[lane] += x[lane] * y[lane];
+        }
+    }
+
+    accumulators.iter().sum::<F>() + remainder
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    #[test]
+    fn it_matches_single_point_evaluation() {
+        for num_of_vars in 0..6u64 {
+            let polynomial = MultilinearPoly::new(
+                (0..1u64 << num_of_vars)
+                    .map(|i| Fq::from(3 * i + 1))
+                    .collect(),
+            );
+            let points: Vec<Vec<Fq>> = (0..5u64)
+                .map(|p| {
+                    (0..num_of_vars)
+                        .map(|i| Fq::from(p * 7 + i * 13 + 2))
+                        .collect()
+                })
+                .collect();
+
+            let expected: Vec<Fq> = points
+                .iter()
+                .map(|point| polynomial.evaluate(point))
+                .collect();
+
+            assert_eq!(polynomial.batch_evaluate(&points), expected);
+        }
+    }
+
+    #[test]
+    fn it_shares_work_between_points_with_a_common_prefix() {
+        let polynomial = MultilinearPoly::new((0..64u64).map(|i| Fq::from(i * i + 5)).collect());
+        let prefix = vec![Fq::from(4), Fq::from(9), Fq::from(2)];
+        let points: Vec<Vec<Fq>> = (0..6u64)
+            .map(|p| {
+                let mut point = if p % 3 == 0 {
+                    vec![Fq::from(p), Fq::from(1), Fq::from(8)]
+                } else {
+                    prefix.clone()
+                };
+                point.extend((0..3u64).map(|i| Fq::from(p * 11 + i)));
+                point
+            })
+            .collect();
+
+        let groups = group_by_prefix(&points, 3);
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups[1], (&prefix[..], vec![1, 2, 4, 5]));
+
+        let expected: Vec<Fq> = points
+            .iter()
+            .map(|point| polynomial.evaluate(point))
+            .collect();
+        assert_eq!(polynomial.batch_evaluate(&points), expected);
+    }
+
+    #[test]
+    fn it_computes_inner_products() {
+        let a: Vec<Fq> = (1..=7u64).map(Fq::from).collect();
+        let b: Vec<Fq> = (1..=7u64).map(|i| Fq::from(i * 2)).collect();
+
+        // 2·(1 + 4 + 9 + 16 + 25 + 36 + 49)
+        assert_eq!(inner_product(&a, &b), Fq::from(280));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of values")]
+    fn it_rejects_points_of_the_wrong_size() {
+        let polynomial = MultilinearPoly::new(vec![Fq::from(1); 4]);
+        let _ = polynomial.batch_evaluate(&[vec![Fq::from(1)]]);
+    }
+}
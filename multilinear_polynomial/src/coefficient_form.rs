@@ -0,0 +1,160 @@
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+use ark_ff::PrimeField;
+use std::fmt;
+
+impl<F: PrimeField> MultilinearPoly<F> {
+    /// Converts hypercube evaluations into monomial coefficients (the Möbius transform).
+    ///
+    /// Coefficient `i` belongs to the monomial made of every variable whose bit is set in `i`,
+    /// using the same bit order as the evaluations: variable 0 is the most significant bit.
+    pub fn to_coefficients(&self) -> Vec<F> {
+        let mut coefficients = self.evaluation.clone();
+
+        for bit in 0..self.num_of_vars {
+            let stride = 1 << bit;
+            for index in 0..coefficients.len() {
+                if index & stride != 0 {
+                    let low = coefficients[index ^ stride];
+                    coefficients[index] -= low;
+                }
+            }
+        }
+
+        coefficients
+    }
+
+    /// Builds a polynomial from monomial coefficients (the inverse Möbius transform).
+    /// Panics if the number of coefficients is not a power of 2.
+    pub fn from_coefficients(coefficients: Vec<F>) -> Self {
+        let mut evaluation = coefficients;
+        let num_of_vars = evaluation.len().ilog2() as usize;
+
+        for bit in 0..num_of_vars {
+            let stride = 1 << bit;
+            for index in 0..evaluation.len() {
+                if index & stride != 0 {
+                    let low = evaluation[index ^ stride];
+                    evaluation[index] += low;
+                }
+            }
+        }
+
+        Self::new(evaluation)
+    }
+}
+
+/// Names variable `i` as a letter when there are few enough, otherwise as `x_i`.
+fn variable_name(index: usize, num_of_vars: usize) -> String {
+    if num_of_vars <= 26 {
+        ((b'a' + index as u8) as char).to_string()
+    } else {
+        format!("x_{}", index)
+    }
+}
+
+/// Renders the polynomial in monomial form, e.g. `3ab + 2b + 7`.
+/// Coefficients closer to the modulus than to zero are shown as negatives.
+impl<F: PrimeField> fmt::Display for MultilinearPoly<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut terms: Vec<(Vec<usize>, F)> = self
+            .to_coefficients()
+            .into_iter()
+            .enumerate()
+            .filter(|(_, coeff)| !coeff.is_zero())
+            .map(|(mask, coeff)| {
+                let variables = (0..self.num_of_vars)
+                    .filter(|i| mask & (1 << (self.num_of_vars - i - 1)) != 0)
+                    .collect();
+                (variables, coeff)
+            })
+            .collect();
+
+        if terms.is_empty() {
+            return write!(f, "0");
+        }
+
+        // Highest degree first, then alphabetical within a degree.
+        terms.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then(a.cmp(b)));
+
+        for (position, (variables, coeff)) in terms.iter().enumerate() {
+            let negative = (-*coeff).into_bigint() < coeff.into_bigint();
+            let magnitude = if negative { -*coeff } else { *coeff };
+
+            match (position, negative) {
+                (0, true) => write!(f, "-")?,
+                (0, false) => {}
+                (_, true) => write!(f, " - ")?,
+                (_, false) => write!(f, " + ")?,
+            }
+
+            if !magnitude.is_one() || variables.is_empty() {
+                write!(f, "{}", magnitude)?;
+            }
+
+            for variable in variables {
+                write!(f, "{}", variable_name(*variable, self.num_of_vars))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    #[test]
+    fn it_converts_evaluations_to_coefficients() {
+        // f(a, b) = 3ab + 2b + 7
+        let polynomial =
+            MultilinearPoly::new(vec![Fq::from(7), Fq::from(9), Fq::from(7), Fq::from(12)]);
+
+        assert_eq!(
+            polynomial.to_coefficients(),
+            vec![Fq::from(7), Fq::from(2), Fq::from(0), Fq::from(3)]
+        );
+    }
+
+    #[test]
+    fn it_round_trips_between_forms() {
+        let coefficients: Vec<Fq> = (0..8u64).map(|i| Fq::from(i * 5 + 1)).collect();
+        let polynomial = MultilinearPoly::from_coefficients(coefficients.clone());
+
+        assert_eq!(polynomial.to_coefficients(), coefficients);
+    }
+
+    #[test]
+    fn it_agrees_with_pen_and_paper_evaluation() {
+        // f(a, b, c) = 2abc + 4ac + 5 at (3, 4, 6): 144 + 72 + 5
+        let mut coefficients = vec![Fq::from(0); 8];
+        coefficients[0b000] = Fq::from(5);
+        coefficients[0b101] = Fq::from(4);
+        coefficients[0b111] = Fq::from(2);
+        let polynomial = MultilinearPoly::from_coefficients(coefficients);
+
+        assert_eq!(
+            polynomial.evaluate(&[Fq::from(3), Fq::from(4), Fq::from(6)]),
+            Fq::from(221)
+        );
+    }
+
+    #[test]
+    fn it_renders_monomial_form() {
+        let polynomial =
+            MultilinearPoly::new(vec![Fq::from(7), Fq::from(9), Fq::from(7), Fq::from(12)]);
+        assert_eq!(polynomial.to_string(), "3ab + 2b + 7");
+
+        let polynomial = MultilinearPoly::from_coefficients(vec![
+            Fq::from(0),
+            -Fq::from(2),
+            Fq::from(1),
+            Fq::from(0),
+        ]);
+        assert_eq!(polynomial.to_string(), "a - 2b");
+
+        let polynomial = MultilinearPoly::new(vec![Fq::from(0); 4]);
+        assert_eq!(polynomial.to_string(), "0");
+    }
+}
@@ -0,0 +1,90 @@
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+use ark_ff::PrimeField;
+
+/// Builds the table of eq(r, x) for every x in {0,1}^n in O(2^n) multiplications.
+/// The first coordinate of `point` is the most significant bit of the table index,
+/// matching the variable order of `MultilinearPoly`.
+pub fn eq_evaluations<F: PrimeField>(point: &[F]) -> Vec<F> {
+    let mut table = Vec::with_capacity(1 << point.len());
+    table.push(F::one());
+
+    for r in point {
+        let mut next = Vec::with_capacity(table.len() * 2);
+
+        for value in table.iter() {
+            let high = *value * r;
+            next.push(*value - high);
+            next.push(high);
+        }
+
+        table = next;
+    }
+
+    table
+}
+
+/// Returns eq(r, x) as a multilinear polynomial over x.
+pub fn eq_polynomial<F: PrimeField>(point: &[F]) -> MultilinearPoly<F> {
+    MultilinearPoly::new(eq_evaluations(point))
+}
+
+/// Evaluates eq(r, s) = Π (r_i·s_i + (1 - r_i)(1 - s_i)) in closed form.
+pub fn eq_evaluate<F: PrimeField>(r: &[F], s: &[F]) -> F {
+    if r.len() != s.len() {
+        panic!("Invalid number of values");
+    }
+
+    r.iter()
+        .zip(s.iter())
+        .map(|(r_i, s_i)| *r_i * s_i + (F::one() - r_i) * (F::one() - s_i))
+        .product()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    #[test]
+    fn it_builds_the_eq_table() {
+        let point = vec![Fq::from(5), Fq::from(1)];
+
+        assert_eq!(
+            eq_evaluations(&point),
+            vec![Fq::from(0), -Fq::from(4), Fq::from(0), Fq::from(5)]
+        );
+    }
+
+    #[test]
+    fn it_matches_closed_form_on_the_hypercube() {
+        let point = vec![Fq::from(3), Fq::from(7), Fq::from(11)];
+        let table = eq_evaluations(&point);
+
+        for (index, value) in table.iter().enumerate() {
+            let bits: Vec<Fq> = (0..3)
+                .map(|i| Fq::from(((index >> (2 - i)) & 1) as u64))
+                .collect();
+
+            assert_eq!(*value, eq_evaluate(&point, &bits));
+        }
+    }
+
+    #[test]
+    fn it_is_an_indicator_on_boolean_points() {
+        let point = vec![Fq::from(1), Fq::from(0), Fq::from(1)];
+        let table = eq_evaluations(&point);
+
+        for (index, value) in table.iter().enumerate() {
+            let expected = if index == 0b101 { 1 } else { 0 };
+            assert_eq!(*value, Fq::from(expected));
+        }
+    }
+
+    #[test]
+    fn it_evaluates_off_the_hypercube() {
+        let r = vec![Fq::from(2), Fq::from(9)];
+        let s = vec![Fq::from(4), Fq::from(6)];
+
+        assert_eq!(eq_evaluate(&r, &s), eq_polynomial(&r).evaluate(&s));
+    }
+}
@@ -0,0 +1,51 @@
+use std::fmt;
+
+/// Errors reported by the fallible `try_`/`checked_` APIs on multilinear polynomials.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MultilinearError {
+    /// The evaluation table is empty or its length is not a power of 2.
+    InvalidEvaluationLength(usize),
+    /// Two operands are defined over different numbers of variables.
+    MismatchedVariables { left: usize, right: usize },
+    /// A point has the wrong number of coordinates.
+    InvalidNumberOfValues { expected: usize, found: usize },
+    /// A variable index is out of range.
+    InvalidVariable { variable: usize, num_of_vars: usize },
+    /// The same variable is fixed more than once.
+    DuplicateVariable(usize),
+}
+
+impl fmt::Display for MultilinearError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MultilinearError::InvalidEvaluationLength(length) => {
+                write!(
+                    f,
+                    "Invalid evaluations: length {} is not a power of 2",
+                    length
+                )
+            }
+            MultilinearError::MismatchedVariables { left, right } => {
+                write!(f, "Mismatched number of variables: {} and {}", left, right)
+            }
+            MultilinearError::InvalidNumberOfValues { expected, found } => write!(
+                f,
+                "Invalid number of values: expected {}, found {}",
+                expected, found
+            ),
+            MultilinearError::InvalidVariable {
+                variable,
+                num_of_vars,
+            } => write!(
+                f,
+                "Invalid variable {} for a polynomial in {} variables",
+                variable, num_of_vars
+            ),
+            MultilinearError::DuplicateVariable(variable) => {
+                write!(f, "Variable {} is fixed more than once", variable)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MultilinearError {}
@@ -1 +1,13 @@
+pub mod batch_evaluation;
+pub mod coefficient_form;
+pub mod eq_polynomial;
+pub mod error;
+pub mod line_restriction;
 pub mod multilinear_polynomial_evaluation;
+pub mod multilinear_view;
+pub mod multivariate_polynomial;
+pub mod sparse_matrix;
+pub mod sparse_multilinear_polynomial;
+pub mod structural_operations;
+pub mod variable_fixing;
+pub mod virtual_polynomial;
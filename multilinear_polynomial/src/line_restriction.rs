@@ -0,0 +1,97 @@
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+use ark_ff::PrimeField;
+use polynomial_utils::interpolation::lagrange_interpolate;
+use polynomial_utils::polynomial::DenseUnivariatePoly;
+use polynomial_utils::univariate::UnivariatePolynomial;
+
+/// Returns the line ℓ(t) = b + t·(c − b) through `b` (at t = 0) and `c` (at t = 1),
+/// as one univariate polynomial per coordinate.
+pub fn line<F: PrimeField>(b: &[F], c: &[F]) -> Vec<DenseUnivariatePoly<F>> {
+    if b.len() != c.len() {
+        panic!("Invalid number of values");
+    }
+
+    b.iter()
+        .zip(c.iter())
+        .map(|(b_i, c_i)| DenseUnivariatePoly::new(vec![*b_i, *c_i - b_i]))
+        .collect()
+}
+
+impl<F: PrimeField> MultilinearPoly<F> {
+    /// Returns q(t) = W(ℓ(t)) where ℓ is the line through `b` and `c`.
+    ///
+    /// Each coordinate of ℓ is linear in t, so q has degree at most n and is recovered
+    /// by interpolating W(ℓ(0)), ..., W(ℓ(n)).
+    pub fn restrict_to_line(&self, b: &[F], c: &[F]) -> DenseUnivariatePoly<F> {
+        if b.len() != self.num_of_vars || c.len() != self.num_of_vars {
+            panic!("Invalid number of values");
+        }
+
+        let line = line(b, c);
+        let t_values: Vec<F> = (0..=self.num_of_vars as u64).map(F::from).collect();
+        let q_values: Vec<F> = t_values
+            .iter()
+            .map(|t| {
+                let point: Vec<F> = line
+                    .iter()
+                    .map(|coordinate| coordinate.evaluate(*t))
+                    .collect();
+                self.evaluate(&point)
+            })
+            .collect();
+
+        lagrange_interpolate(&t_values, &q_values)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    #[test]
+    fn it_builds_the_line_through_two_points() {
+        let b = vec![Fq::from(1), Fq::from(4)];
+        let c = vec![Fq::from(3), Fq::from(2)];
+        let line = line(&b, &c);
+
+        let at = |t: u64| -> Vec<Fq> {
+            line.iter()
+                .map(|coordinate| coordinate.evaluate(Fq::from(t)))
+                .collect()
+        };
+
+        assert_eq!(at(0), b);
+        assert_eq!(at(1), c);
+        assert_eq!(at(2), vec![Fq::from(5), Fq::from(0)]);
+    }
+
+    #[test]
+    fn it_restricts_to_a_line() {
+        let polynomial = MultilinearPoly::new(vec![
+            Fq::from(3),
+            Fq::from(1),
+            Fq::from(4),
+            Fq::from(1),
+            Fq::from(5),
+            Fq::from(9),
+            Fq::from(2),
+            Fq::from(6),
+        ]);
+        let b = vec![Fq::from(2), Fq::from(7), Fq::from(1)];
+        let c = vec![Fq::from(8), Fq::from(3), Fq::from(5)];
+
+        let q = polynomial.restrict_to_line(&b, &c);
+
+        assert!(q.degree() <= Some(3));
+        assert_eq!(q.evaluate(Fq::from(0)), polynomial.evaluate(&b));
+        assert_eq!(q.evaluate(Fq::from(1)), polynomial.evaluate(&c));
+
+        let t = Fq::from(11);
+        let point: Vec<Fq> = line(&b, &c)
+            .iter()
+            .map(|coordinate| coordinate.evaluate(t))
+            .collect();
+        assert_eq!(q.evaluate(t), polynomial.evaluate(&point));
+    }
+}
@@ -1,5 +1,7 @@
+use crate::eq_polynomial::eq_evaluations;
+use crate::error::MultilinearError;
 use ark_ff::{BigInteger, PrimeField};
-use std::ops::{Add, Mul};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct MultilinearPoly<F: PrimeField> {
@@ -9,16 +11,19 @@ pub struct MultilinearPoly<F: PrimeField> {
 
 impl<F: PrimeField> MultilinearPoly<F> {
     pub fn new(evaluations: Vec<F>) -> Self {
-        let num_of_vars: usize = evaluations.len().ilog2() as usize;
+        Self::try_new(evaluations).unwrap_or_else(|err| panic!("{}", err))
+    }
 
-        if evaluations.len() != 1 << num_of_vars {
-            panic!("Invalid evaluations");
+    /// Builds a polynomial, reporting an error if the length is not a power of 2.
+    pub fn try_new(evaluations: Vec<F>) -> Result<Self, MultilinearError> {
+        if !evaluations.len().is_power_of_two() {
+            return Err(MultilinearError::InvalidEvaluationLength(evaluations.len()));
         }
 
-        Self {
+        Ok(Self {
+            num_of_vars: evaluations.len().ilog2() as usize,
             evaluation: evaluations,
-            num_of_vars,
-        }
+        })
     }
 
     /// Returns the number of variables in the multilinear polynomial.
@@ -40,6 +45,19 @@ impl<F: PrimeField> MultilinearPoly<F> {
     }
 
     pub fn partial_evaluate(&self, bit: usize, value: &F) -> Self {
+        self.try_partial_evaluate(bit, value)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Fixes variable `bit` to `value`, reporting an error if the variable does not exist.
+    pub fn try_partial_evaluate(&self, bit: usize, value: &F) -> Result<Self, MultilinearError> {
+        if bit >= self.num_of_vars {
+            return Err(MultilinearError::InvalidVariable {
+                variable: bit,
+                num_of_vars: self.num_of_vars,
+            });
+        }
+
         let mut result: Vec<F> = Vec::new();
 
         for (a, b) in MultilinearPoly::<F>::pair_points(bit, self.num_of_vars).into_iter() {
@@ -49,17 +67,12 @@ impl<F: PrimeField> MultilinearPoly<F> {
             result.push(a + *value * (b - a));
         }
 
-        Self::new(result)
+        Self::try_new(result)
     }
 
+    /// Fixes the leading variables to `values`; see `fix_prefix`.
     pub fn multi_partial_evaluate(&self, values: &[F]) -> Self {
-        let mut poly = self.clone();
-
-        for value in values {
-            poly = poly.partial_evaluate(0, value);
-        }
-
-        poly
+        self.fix_prefix(values)
     }
 
     pub fn convert_to_bytes(&self) -> Vec<u8> {
@@ -72,18 +85,26 @@ impl<F: PrimeField> MultilinearPoly<F> {
         bytes
     }
 
-    pub fn evaluate(&self, values: Vec<F>) -> F {
-        if values.len() != self.num_of_vars {
-            panic!("Invalid number of values");
-        }
-
-        let mut result = self.clone();
+    pub fn evaluate(&self, values: &[F]) -> F {
+        self.try_evaluate(values)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
 
-        for value in values.iter() {
-            result = result.partial_evaluate(0, value);
+    /// Evaluates at a point, reporting an error if it has the wrong number of coordinates.
+    pub fn try_evaluate(&self, values: &[F]) -> Result<F, MultilinearError> {
+        if values.len() != self.num_of_vars {
+            return Err(MultilinearError::InvalidNumberOfValues {
+                expected: self.num_of_vars,
+                found: values.len(),
+            });
         }
 
-        result.evaluation[0]
+        // W(r) = Σ_x eq(r, x)·W(x)
+        Ok(eq_evaluations(values)
+            .iter()
+            .zip(self.evaluation.iter())
+            .map(|(eq, eval)| *eq * eval)
+            .sum())
     }
 
     pub fn scale(&self, value: F) -> Self {
@@ -91,35 +112,121 @@ impl<F: PrimeField> MultilinearPoly<F> {
 
         Self::new(result)
     }
-}
 
-impl<F: PrimeField> Add for MultilinearPoly<F> {
-    type Output = Self;
+    /// Combines two polynomials evaluation by evaluation, reporting mismatched variable counts.
+    fn zip_with(&self, other: &Self, op: impl Fn(F, F) -> F) -> Result<Self, MultilinearError> {
+        if self.num_of_vars != other.num_of_vars {
+            return Err(MultilinearError::MismatchedVariables {
+                left: self.num_of_vars,
+                right: other.num_of_vars,
+            });
+        }
 
-    fn add(self, other: Self) -> Self {
         let result = self
             .evaluation
             .iter()
             .zip(other.evaluation.iter())
-            .map(|(a, b)| *a + *b)
+            .map(|(a, b)| op(*a, *b))
             .collect();
 
-        MultilinearPoly::new(result)
+        Ok(Self {
+            evaluation: result,
+            num_of_vars: self.num_of_vars,
+        })
+    }
+
+    pub fn checked_add(&self, other: &Self) -> Result<Self, MultilinearError> {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, MultilinearError> {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    /// Multiplies evaluations pointwise; see the note on `Mul`.
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, MultilinearError> {
+        self.zip_with(other, |a, b| a * b)
     }
 }
 
+impl<F: PrimeField> Add for MultilinearPoly<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl<F: PrimeField> Add<&MultilinearPoly<F>> for &MultilinearPoly<F> {
+    type Output = MultilinearPoly<F>;
+
+    fn add(self, other: &MultilinearPoly<F>) -> MultilinearPoly<F> {
+        self.checked_add(other)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl<F: PrimeField> AddAssign<&MultilinearPoly<F>> for MultilinearPoly<F> {
+    fn add_assign(&mut self, other: &MultilinearPoly<F>) {
+        *self = &*self + other;
+    }
+}
+
+impl<F: PrimeField> Sub for MultilinearPoly<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        &self - &other
+    }
+}
+
+impl<F: PrimeField> Sub<&MultilinearPoly<F>> for &MultilinearPoly<F> {
+    type Output = MultilinearPoly<F>;
+
+    fn sub(self, other: &MultilinearPoly<F>) -> MultilinearPoly<F> {
+        self.checked_sub(other)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+}
+
+impl<F: PrimeField> SubAssign<&MultilinearPoly<F>> for MultilinearPoly<F> {
+    fn sub_assign(&mut self, other: &MultilinearPoly<F>) {
+        *self = &*self - other;
+    }
+}
+
+impl<F: PrimeField> Neg for MultilinearPoly<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+impl<F: PrimeField> Neg for &MultilinearPoly<F> {
+    type Output = MultilinearPoly<F>;
+
+    fn neg(self) -> MultilinearPoly<F> {
+        self.scale(-F::one())
+    }
+}
+
+/// Multiplies evaluations pointwise. The result agrees with the true product only on the
+/// hypercube; use `VirtualPolynomial` when the product must be evaluated elsewhere.
 impl<F: PrimeField> Mul for MultilinearPoly<F> {
     type Output = Self;
 
     fn mul(self, other: Self) -> Self {
-        let result = self
-            .evaluation
-            .iter()
-            .zip(other.evaluation.iter())
-            .map(|(a, b)| *a * *b)
-            .collect();
+        &self * &other
+    }
+}
+
+impl<F: PrimeField> Mul<&MultilinearPoly<F>> for &MultilinearPoly<F> {
+    type Output = MultilinearPoly<F>;
 
-        MultilinearPoly::new(result)
+    fn mul(self, other: &MultilinearPoly<F>) -> MultilinearPoly<F> {
+        self.checked_mul(other)
+            .unwrap_or_else(|err| panic!("{}", err))
     }
 }
 
@@ -156,8 +263,70 @@ mod test {
 
         let values = vec![Fq::from(5), Fq::from(1)];
 
-        let result = polynomial.evaluate(values);
+        let result = polynomial.evaluate(&values);
 
         assert_eq!(result, Fq::from(50));
     }
+
+    #[test]
+    fn it_reports_invalid_inputs() {
+        assert_eq!(
+            MultilinearPoly::<Fq>::try_new(vec![Fq::from(1); 3]),
+            Err(MultilinearError::InvalidEvaluationLength(3))
+        );
+        assert_eq!(
+            MultilinearPoly::<Fq>::try_new(vec![]),
+            Err(MultilinearError::InvalidEvaluationLength(0))
+        );
+
+        let polynomial = MultilinearPoly::new(vec![Fq::from(1); 4]);
+        assert_eq!(
+            polynomial.try_evaluate(&[Fq::from(1)]),
+            Err(MultilinearError::InvalidNumberOfValues {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            polynomial.try_partial_evaluate(2, &Fq::from(1)),
+            Err(MultilinearError::InvalidVariable {
+                variable: 2,
+                num_of_vars: 2
+            })
+        );
+    }
+
+    #[test]
+    fn it_reports_mismatched_variables() {
+        let left = MultilinearPoly::new(vec![Fq::from(1); 4]);
+        let right = MultilinearPoly::new(vec![Fq::from(1); 2]);
+
+        assert_eq!(
+            left.checked_add(&right),
+            Err(MultilinearError::MismatchedVariables { left: 2, right: 1 })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Mismatched number of variables")]
+    fn it_panics_on_mismatched_addition() {
+        let left = MultilinearPoly::new(vec![Fq::from(1); 4]);
+        let right = MultilinearPoly::new(vec![Fq::from(1); 2]);
+
+        let _ = left + right;
+    }
+
+    #[test]
+    fn it_supports_arithmetic_operators() {
+        let a = MultilinearPoly::new(vec![Fq::from(1), Fq::from(2), Fq::from(3), Fq::from(4)]);
+        let b = MultilinearPoly::new(vec![Fq::from(5), Fq::from(0), Fq::from(7), Fq::from(2)]);
+
+        assert_eq!(&(&a + &b) - &b, a);
+        assert_eq!(&a + &(-&a), MultilinearPoly::new(vec![Fq::from(0); 4]));
+
+        let mut c = a.clone();
+        c += &b;
+        c -= &a;
+        assert_eq!(c, b);
+    }
 }
@@ -0,0 +1,197 @@
+use crate::eq_polynomial::eq_evaluations;
+use crate::error::MultilinearError;
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+use ark_ff::{BigInteger, PrimeField};
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// A read-only multilinear polynomial over storage it does not necessarily own.
+///
+/// `S` can be a borrowed slice, an `Arc<[F]>` shared between protocols, or an owned
+/// `Vec<F>`. Read-only operations work in place; operations that produce a new table,
+/// such as `partial_evaluate`, allocate only the (halved) result.
+#[derive(Clone, Debug)]
+pub struct MultilinearView<F: PrimeField, S: AsRef<[F]>> {
+    storage: S,
+    num_of_vars: usize,
+    _phantom: PhantomData<F>,
+}
+
+/// A view borrowing its evaluations.
+pub type BorrowedMultilinear<'a, F> = MultilinearView<F, &'a [F]>;
+
+/// A view sharing its evaluations through reference counting.
+pub type SharedMultilinear<F> = MultilinearView<F, Arc<[F]>>;
+
+impl<F: PrimeField, S: AsRef<[F]>> MultilinearView<F, S> {
+    pub fn new(storage: S) -> Self {
+        Self::try_new(storage).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Wraps the storage, reporting an error if its length is not a power of 2.
+    pub fn try_new(storage: S) -> Result<Self, MultilinearError> {
+        let length = storage.as_ref().len();
+        if !length.is_power_of_two() {
+            return Err(MultilinearError::InvalidEvaluationLength(length));
+        }
+
+        Ok(Self {
+            storage,
+            num_of_vars: length.ilog2() as usize,
+            _phantom: PhantomData,
+        })
+    }
+
+    /// Returns the number of variables in the multilinear polynomial.
+    pub fn number_of_variables(&self) -> usize {
+        self.num_of_vars
+    }
+
+    pub fn evaluations(&self) -> &[F] {
+        self.storage.as_ref()
+    }
+
+    /// Returns the underlying storage.
+    pub fn into_inner(self) -> S {
+        self.storage
+    }
+
+    /// Sums the evaluations over the hypercube.
+    pub fn sum(&self) -> F {
+        self.evaluations().iter().sum()
+    }
+
+    pub fn evaluate(&self, values: &[F]) -> F {
+        if values.len() != self.num_of_vars {
+            panic!("Invalid number of values");
+        }
+
+        eq_evaluations(values)
+            .iter()
+            .zip(self.evaluations().iter())
+            .map(|(eq, eval)| *eq * eval)
+            .sum()
+    }
+
+    /// Fixes variable `bit` to `value`, returning an owned polynomial in one fewer variable.
+    pub fn partial_evaluate(&self, bit: usize, value: &F) -> MultilinearPoly<F> {
+        if bit >= self.num_of_vars {
+            panic!(
+                "{}",
+                MultilinearError::InvalidVariable {
+                    variable: bit,
+                    num_of_vars: self.num_of_vars,
+                }
+            );
+        }
+
+        let evaluations = self.evaluations();
+        let stride = 1 << (self.num_of_vars - bit - 1);
+        let mut result = Vec::with_capacity(evaluations.len() / 2);
+
+        for block in evaluations.chunks_exact(2 * stride) {
+            let (low, high) = block.split_at(stride);
+            for (a, b) in low.iter().zip(high.iter()) {
+                result.push(*a + *value * (*b - a));
+            }
+        }
+
+        MultilinearPoly::new(result)
+    }
+
+    pub fn convert_to_bytes(&self) -> Vec<u8> {
+        self.evaluations()
+            .iter()
+            .flat_map(|value| value.into_bigint().to_bytes_be())
+            .collect()
+    }
+
+    /// Copies the evaluations into an owned `MultilinearPoly`.
+    pub fn to_multilinear(&self) -> MultilinearPoly<F> {
+        MultilinearPoly::new(self.evaluations().to_vec())
+    }
+}
+
+// Views compare by their evaluations, whatever storage backs them.
+impl<F: PrimeField, S: AsRef<[F]>> PartialEq for MultilinearView<F, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.evaluations() == other.evaluations()
+    }
+}
+
+impl<F: PrimeField> SharedMultilinear<F> {
+    /// True when both views read the same shared table, rather than merely equal ones.
+    pub fn shares_storage_with(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.storage, &other.storage)
+    }
+}
+
+impl<F: PrimeField> MultilinearPoly<F> {
+    /// Borrows the evaluations as a view.
+    pub fn as_view(&self) -> BorrowedMultilinear<'_, F> {
+        MultilinearView::new(&self.evaluation)
+    }
+
+    /// Moves the evaluations into a view that can be cheaply shared between protocols.
+    pub fn into_shared(self) -> SharedMultilinear<F> {
+        MultilinearView::new(Arc::from(self.evaluation))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn test_setup() -> Vec<Fq> {
+        (0..8u64).map(|i| Fq::from(i * i + 1)).collect()
+    }
+
+    #[test]
+    fn it_matches_the_owned_polynomial() {
+        let evaluations = test_setup();
+        let polynomial = MultilinearPoly::new(evaluations.clone());
+        let view = MultilinearView::new(evaluations.as_slice());
+        let point = vec![Fq::from(3), Fq::from(5), Fq::from(7)];
+
+        assert_eq!(view.evaluate(&point), polynomial.evaluate(&point));
+        assert_eq!(view.convert_to_bytes(), polynomial.convert_to_bytes());
+
+        for bit in 0..3 {
+            assert_eq!(
+                view.partial_evaluate(bit, &Fq::from(4)),
+                polynomial.partial_evaluate(bit, &Fq::from(4))
+            );
+        }
+    }
+
+    #[test]
+    fn it_shares_storage_without_copying() {
+        let shared = MultilinearPoly::new(test_setup()).into_shared();
+        let other = shared.clone();
+
+        assert!(Arc::ptr_eq(
+            &shared.clone().into_inner(),
+            &other.into_inner()
+        ));
+        assert_eq!(shared.sum(), Fq::from(148));
+    }
+
+    #[test]
+    fn it_works_over_owned_storage() {
+        let view = MultilinearView::new(test_setup());
+
+        assert_eq!(view.number_of_variables(), 3);
+        assert_eq!(view.to_multilinear(), MultilinearPoly::new(test_setup()));
+    }
+
+    #[test]
+    fn it_reports_invalid_storage() {
+        let evaluations = vec![Fq::from(1); 6];
+
+        assert_eq!(
+            MultilinearView::try_new(evaluations.as_slice()).err(),
+            Some(MultilinearError::InvalidEvaluationLength(6))
+        );
+    }
+}
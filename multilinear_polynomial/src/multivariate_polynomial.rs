@@ -0,0 +1,397 @@
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+use ark_ff::{BigInteger, PrimeField};
+use std::collections::BTreeMap;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// A multivariate polynomial of arbitrary individual degree, stored as its non-zero terms.
+/// Each monomial is an exponent vector: `[2, 0, 1]` is x_0²·x_2.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMultivariatePoly<F: PrimeField> {
+    pub terms: BTreeMap<Vec<usize>, F>, // exponent vector -> coefficient
+    pub num_of_vars: usize,
+}
+
+impl<F: PrimeField> SparseMultivariatePoly<F> {
+    /// Builds a polynomial from (coefficient, exponents) pairs. Like terms are combined and
+    /// zero coefficients dropped. Panics if an exponent vector has the wrong length.
+    pub fn new(num_of_vars: usize, terms: Vec<(F, Vec<usize>)>) -> Self {
+        let mut poly = Self::zero(num_of_vars);
+
+        for (coefficient, exponents) in terms {
+            if exponents.len() != num_of_vars {
+                panic!("Invalid exponent vector");
+            }
+            poly.insert_term(exponents, coefficient);
+        }
+
+        poly
+    }
+
+    pub fn zero(num_of_vars: usize) -> Self {
+        Self {
+            terms: BTreeMap::new(),
+            num_of_vars,
+        }
+    }
+
+    pub fn number_of_variables(&self) -> usize {
+        self.num_of_vars
+    }
+
+    /// Returns the number of non-zero terms stored.
+    pub fn num_terms(&self) -> usize {
+        self.terms.len()
+    }
+
+    fn insert_term(&mut self, exponents: Vec<usize>, coefficient: F) {
+        let entry = self.terms.entry(exponents.clone()).or_insert(F::zero());
+        *entry += coefficient;
+
+        if entry.is_zero() {
+            self.terms.remove(&exponents);
+        }
+    }
+
+    /// Returns the degree of the polynomial in `variable` alone.
+    pub fn degree(&self, variable: usize) -> usize {
+        if variable >= self.num_of_vars {
+            panic!("Invalid variable");
+        }
+
+        self.terms
+            .keys()
+            .map(|exponents| exponents[variable])
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the degree in each variable: the bounds a sumcheck verifier checks round
+    /// polynomials against.
+    pub fn degree_bounds(&self) -> Vec<usize> {
+        (0..self.num_of_vars)
+            .map(|variable| self.degree(variable))
+            .collect()
+    }
+
+    pub fn total_degree(&self) -> usize {
+        self.terms
+            .keys()
+            .map(|exponents| exponents.iter().sum())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Evaluates at a point with one coordinate per variable.
+    pub fn evaluate(&self, values: &[F]) -> F {
+        if values.len() != self.num_of_vars {
+            panic!("Invalid number of values");
+        }
+
+        self.terms
+            .iter()
+            .map(|(exponents, coefficient)| {
+                values
+                    .iter()
+                    .zip(exponents.iter())
+                    .fold(*coefficient, |acc, (value, exponent)| {
+                        acc * value.pow([*exponent as u64])
+                    })
+            })
+            .sum()
+    }
+
+    /// Fixes `variable` to `value`, removing it from the polynomial.
+    pub fn partial_evaluate(&self, variable: usize, value: &F) -> Self {
+        if variable >= self.num_of_vars {
+            panic!("Invalid variable");
+        }
+
+        let mut result = Self::zero(self.num_of_vars - 1);
+
+        for (exponents, coefficient) in &self.terms {
+            let mut reduced = exponents.clone();
+            let exponent = reduced.remove(variable);
+            result.insert_term(reduced, *coefficient * value.pow([exponent as u64]));
+        }
+
+        result
+    }
+
+    /// Sums over {0,1}^n in one pass over the terms: on the hypercube x^e = x for e ≥ 1, so
+    /// a monomial sums to 2^(number of variables it does not contain).
+    pub fn sum_over_hypercube(&self) -> F {
+        self.terms
+            .iter()
+            .map(|(exponents, coefficient)| {
+                let absent = exponents.iter().filter(|exponent| **exponent == 0).count();
+                *coefficient * F::from(2u64).pow([absent as u64])
+            })
+            .sum()
+    }
+
+    /// Moves the polynomial into `num_of_vars` variables, renaming variable i to
+    /// `mapping[i]`. Panics if the mapping is out of range or not injective.
+    pub fn embed(&self, num_of_vars: usize, mapping: &[usize]) -> Self {
+        let mut targets = mapping.to_vec();
+        targets.sort_unstable();
+        targets.dedup();
+        if mapping.len() != self.num_of_vars
+            || targets.len() != mapping.len()
+            || targets.last().is_some_and(|last| *last >= num_of_vars)
+        {
+            panic!("Invalid variable mapping");
+        }
+
+        let mut result = Self::zero(num_of_vars);
+
+        for (exponents, coefficient) in &self.terms {
+            let mut embedded = vec![0; num_of_vars];
+            for (exponent, target) in exponents.iter().zip(mapping.iter()) {
+                embedded[*target] = *exponent;
+            }
+            result.insert_term(embedded, *coefficient);
+        }
+
+        result
+    }
+
+    pub fn scale(&self, value: F) -> Self {
+        let mut result = Self::zero(self.num_of_vars);
+
+        for (exponents, coefficient) in &self.terms {
+            result.insert_term(exponents.clone(), *coefficient * value);
+        }
+
+        result
+    }
+
+    pub fn convert_to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        for (exponents, coefficient) in &self.terms {
+            for exponent in exponents {
+                bytes.extend((*exponent as u64).to_be_bytes());
+            }
+            bytes.extend(coefficient.into_bigint().to_bytes_be());
+        }
+
+        bytes
+    }
+
+    fn check_variables(&self, other: &Self) {
+        if self.num_of_vars != other.num_of_vars {
+            panic!(
+                "Mismatched number of variables: {} and {}",
+                self.num_of_vars, other.num_of_vars
+            );
+        }
+    }
+}
+
+impl<F: PrimeField> Add<&SparseMultivariatePoly<F>> for &SparseMultivariatePoly<F> {
+    type Output = SparseMultivariatePoly<F>;
+
+    fn add(self, other: &SparseMultivariatePoly<F>) -> SparseMultivariatePoly<F> {
+        self.check_variables(other);
+        let mut result = self.clone();
+
+        for (exponents, coefficient) in &other.terms {
+            result.insert_term(exponents.clone(), *coefficient);
+        }
+
+        result
+    }
+}
+
+impl<F: PrimeField> Add for SparseMultivariatePoly<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        &self + &other
+    }
+}
+
+impl<F: PrimeField> Neg for &SparseMultivariatePoly<F> {
+    type Output = SparseMultivariatePoly<F>;
+
+    fn neg(self) -> SparseMultivariatePoly<F> {
+        self.scale(-F::one())
+    }
+}
+
+impl<F: PrimeField> Neg for SparseMultivariatePoly<F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+impl<F: PrimeField> Sub<&SparseMultivariatePoly<F>> for &SparseMultivariatePoly<F> {
+    type Output = SparseMultivariatePoly<F>;
+
+    fn sub(self, other: &SparseMultivariatePoly<F>) -> SparseMultivariatePoly<F> {
+        self + &(-other)
+    }
+}
+
+impl<F: PrimeField> Sub for SparseMultivariatePoly<F> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        &self - &other
+    }
+}
+
+impl<F: PrimeField> Mul<&SparseMultivariatePoly<F>> for &SparseMultivariatePoly<F> {
+    type Output = SparseMultivariatePoly<F>;
+
+    fn mul(self, other: &SparseMultivariatePoly<F>) -> SparseMultivariatePoly<F> {
+        self.check_variables(other);
+        let mut result = SparseMultivariatePoly::zero(self.num_of_vars);
+
+        for (a_exponents, a) in &self.terms {
+            for (b_exponents, b) in &other.terms {
+                result.insert_term(multiply_monomials(a_exponents, b_exponents), *a * b);
+            }
+        }
+
+        result
+    }
+}
+
+impl<F: PrimeField> Mul for SparseMultivariatePoly<F> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        &self * &other
+    }
+}
+
+fn multiply_monomials(a: &[usize], b: &[usize]) -> Vec<usize> {
+    a.iter().zip(b.iter()).map(|(x, y)| x + y).collect()
+}
+
+/// Converts through the monomial coefficients, so every exponent is 0 or 1.
+impl<F: PrimeField> From<&MultilinearPoly<F>> for SparseMultivariatePoly<F> {
+    fn from(poly: &MultilinearPoly<F>) -> Self {
+        let n = poly.num_of_vars;
+        let terms = poly
+            .to_coefficients()
+            .into_iter()
+            .enumerate()
+            .map(|(index, coefficient)| {
+                let exponents = (0..n).map(|variable| (index >> (n - 1 - variable)) & 1);
+                (coefficient, exponents.collect())
+            })
+            .collect();
+
+        Self::new(n, terms)
+    }
+}
+
+impl<F: PrimeField> From<MultilinearPoly<F>> for SparseMultivariatePoly<F> {
+    fn from(poly: MultilinearPoly<F>) -> Self {
+        Self::from(&poly)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    // 3x² y + 2 y z³ + 5
+    fn test_polynomial() -> SparseMultivariatePoly<Fq> {
+        SparseMultivariatePoly::new(
+            3,
+            vec![
+                (Fq::from(3), vec![2, 1, 0]),
+                (Fq::from(2), vec![0, 1, 3]),
+                (Fq::from(5), vec![0, 0, 0]),
+            ],
+        )
+    }
+
+    #[test]
+    fn it_evaluates_and_reports_degrees() {
+        let poly = test_polynomial();
+
+        // 3·4·3 + 2·3·1 + 5
+        assert_eq!(
+            poly.evaluate(&[Fq::from(2), Fq::from(3), Fq::from(1)]),
+            Fq::from(47)
+        );
+        assert_eq!(poly.degree_bounds(), vec![2, 1, 3]);
+        assert_eq!(poly.total_degree(), 4);
+    }
+
+    #[test]
+    fn it_partially_evaluates() {
+        let poly = test_polynomial();
+        let fixed = poly.partial_evaluate(1, &Fq::from(3));
+
+        assert_eq!(fixed.number_of_variables(), 2);
+        assert_eq!(
+            fixed.evaluate(&[Fq::from(2), Fq::from(1)]),
+            poly.evaluate(&[Fq::from(2), Fq::from(3), Fq::from(1)])
+        );
+    }
+
+    #[test]
+    fn it_sums_over_the_hypercube() {
+        let poly = test_polynomial();
+        let expected: Fq = (0..8u64)
+            .map(|i| {
+                let point: Vec<Fq> = (0..3).map(|bit| Fq::from((i >> (2 - bit)) & 1)).collect();
+                poly.evaluate(&point)
+            })
+            .sum();
+
+        assert_eq!(poly.sum_over_hypercube(), expected);
+    }
+
+    #[test]
+    fn it_supports_arithmetic() {
+        let a = test_polynomial();
+        let b = SparseMultivariatePoly::new(3, vec![(Fq::from(1), vec![1, 0, 0])]);
+        let point = [Fq::from(4), Fq::from(5), Fq::from(6)];
+
+        assert_eq!(
+            (&a + &b).evaluate(&point),
+            a.evaluate(&point) + b.evaluate(&point)
+        );
+        assert_eq!(
+            (&a - &b).evaluate(&point),
+            a.evaluate(&point) - b.evaluate(&point)
+        );
+        assert_eq!(
+            (&a * &b).evaluate(&point),
+            a.evaluate(&point) * b.evaluate(&point)
+        );
+        assert_eq!((&a * &b).degree(0), 3);
+        assert_eq!((a.clone() - a).num_terms(), 0);
+    }
+
+    #[test]
+    fn it_converts_from_multilinear() {
+        let multilinear =
+            MultilinearPoly::new(vec![Fq::from(0), Fq::from(0), Fq::from(3), Fq::from(10)]);
+        let poly = SparseMultivariatePoly::from(&multilinear);
+        let point = vec![Fq::from(5), Fq::from(7)];
+
+        assert_eq!(poly.degree_bounds(), vec![1, 1]);
+        assert_eq!(poly.evaluate(&point), multilinear.evaluate(&point));
+    }
+
+    #[test]
+    fn it_embeds_into_more_variables() {
+        let poly = test_polynomial().embed(4, &[3, 0, 1]);
+        let point = [Fq::from(3), Fq::from(1), Fq::from(9), Fq::from(2)];
+
+        assert_eq!(
+            poly.evaluate(&point),
+            test_polynomial().evaluate(&[Fq::from(2), Fq::from(3), Fq::from(1)])
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::eq_polynomial::eq_evaluations;
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+use crate::sparse_multilinear_polynomial::SparseMultilinearPoly;
+use ark_ff::PrimeField;
+
+/// The multilinear extension Ã(x, y) of a sparse matrix, over row bits x then column bits y.
+///
+/// Only the non-zero (row, col, value) entries are stored. Dimensions are rounded up to
+/// powers of 2, so padding rows and columns are zero.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMatrixMle<F: PrimeField> {
+    pub num_row_vars: usize,
+    pub num_col_vars: usize,
+    pub entries: Vec<(usize, usize, F)>,
+}
+
+impl<F: PrimeField> SparseMatrixMle<F> {
+    /// Panics if an entry lies outside a `num_rows` × `num_cols` matrix.
+    pub fn new(num_rows: usize, num_cols: usize, entries: Vec<(usize, usize, F)>) -> Self {
+        if entries
+            .iter()
+            .any(|(row, col, _)| *row >= num_rows || *col >= num_cols)
+        {
+            panic!("Matrix entry out of range");
+        }
+
+        Self {
+            num_row_vars: num_rows.next_power_of_two().ilog2() as usize,
+            num_col_vars: num_cols.next_power_of_two().ilog2() as usize,
+            entries: entries
+                .into_iter()
+                .filter(|(_, _, value)| !value.is_zero())
+                .collect(),
+        }
+    }
+
+    /// Returns the number of variables in the multilinear extension.
+    pub fn number_of_variables(&self) -> usize {
+        self.num_row_vars + self.num_col_vars
+    }
+
+    /// Returns Ã as a sparse multilinear polynomial, with the row bits as leading variables.
+    pub fn to_sparse_multilinear(&self) -> SparseMultilinearPoly<F> {
+        let evaluations = self
+            .entries
+            .iter()
+            .map(|(row, col, value)| (row << self.num_col_vars | col, *value))
+            .collect();
+
+        SparseMultilinearPoly::new(self.number_of_variables(), evaluations)
+    }
+
+    /// Evaluates Ã(r_x, r_y) in O(nnz + 2^|r_x| + 2^|r_y|) without materialising the matrix.
+    pub fn evaluate(&self, r_x: &[F], r_y: &[F]) -> F {
+        if r_x.len() != self.num_row_vars || r_y.len() != self.num_col_vars {
+            panic!("Invalid number of values");
+        }
+
+        let row_weights = eq_evaluations(r_x);
+        let col_weights = eq_evaluations(r_y);
+
+        self.entries
+            .iter()
+            .map(|(row, col, value)| *value * row_weights[*row] * col_weights[*col])
+            .sum()
+    }
+
+    /// Fixes the row variables to `r_x`, returning the column MLE y ↦ Ã(r_x, y).
+    pub fn partial_evaluate_rows(&self, r_x: &[F]) -> MultilinearPoly<F> {
+        if r_x.len() != self.num_row_vars {
+            panic!("Invalid number of values");
+        }
+
+        let row_weights = eq_evaluations(r_x);
+        let mut evaluation = vec![F::zero(); 1 << self.num_col_vars];
+
+        for (row, col, value) in &self.entries {
+            evaluation[*col] += *value * row_weights[*row];
+        }
+
+        MultilinearPoly::new(evaluation)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn test_setup() -> SparseMatrixMle<Fq> {
+        // 3 × 4 matrix, padded to 4 × 4
+        SparseMatrixMle::new(
+            3,
+            4,
+            vec![
+                (0, 1, Fq::from(5)),
+                (1, 3, Fq::from(2)),
+                (2, 0, Fq::from(7)),
+                (2, 2, Fq::from(1)),
+            ],
+        )
+    }
+
+    #[test]
+    fn it_matches_the_dense_extension() {
+        let matrix = test_setup();
+        let dense = matrix.to_sparse_multilinear().to_dense();
+        let r_x = vec![Fq::from(3), Fq::from(8)];
+        let r_y = vec![Fq::from(6), Fq::from(2)];
+
+        assert_eq!(dense.evaluation[2 << 2], Fq::from(7));
+        assert_eq!(
+            matrix.evaluate(&r_x, &r_y),
+            dense.evaluate(&[r_x, r_y].concat())
+        );
+    }
+
+    #[test]
+    fn it_fixes_row_variables() {
+        let matrix = test_setup();
+        let r_x = vec![Fq::from(3), Fq::from(8)];
+        let r_y = vec![Fq::from(6), Fq::from(2)];
+
+        let column_mle = matrix.partial_evaluate_rows(&r_x);
+
+        assert_eq!(column_mle.num_of_vars, 2);
+        assert_eq!(column_mle.evaluate(&r_y), matrix.evaluate(&r_x, &r_y));
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix entry out of range")]
+    fn it_rejects_out_of_range_entries() {
+        let _ = SparseMatrixMle::new(2, 2, vec![(2, 0, Fq::from(1))]);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+use ark_ff::PrimeField;
+use std::collections::BTreeMap;
+use std::ops::Add;
+
+// Sparse Representation: Only stores the non-zero evaluations over the hypercube
+#[derive(Clone, Debug, PartialEq)]
+pub struct SparseMultilinearPoly<F: PrimeField> {
+    pub evaluations: BTreeMap<usize, F>, // hypercube index -> evaluation
+    pub num_of_vars: usize,
+}
+
+impl<F: PrimeField> SparseMultilinearPoly<F> {
+    /// Builds a sparse polynomial from (index, value) pairs. Zero values are dropped and
+    /// repeated indices are summed. Panics if an index lies outside the hypercube.
+    pub fn new(num_of_vars: usize, evaluations: Vec<(usize, F)>) -> Self {
+        let mut poly = Self {
+            evaluations: BTreeMap::new(),
+            num_of_vars,
+        };
+
+        for (index, value) in evaluations {
+            if index >= 1 << num_of_vars {
+                panic!("Invalid evaluation index");
+            }
+            poly.insert_term(index, value);
+        }
+
+        poly
+    }
+
+    /// Returns the number of variables in the multilinear polynomial.
+    pub fn number_of_variables(&self) -> usize {
+        self.num_of_vars
+    }
+
+    /// Returns the number of non-zero evaluations stored.
+    pub fn num_non_zero(&self) -> usize {
+        self.evaluations.len()
+    }
+
+    fn insert_term(&mut self, index: usize, value: F) {
+        let entry = self.evaluations.entry(index).or_insert(F::zero());
+        *entry += value;
+
+        if entry.is_zero() {
+            self.evaluations.remove(&index);
+        }
+    }
+
+    pub fn partial_evaluate(&self, bit: usize, value: &F) -> Self {
+        let inverted_index = self.num_of_vars - bit - 1;
+        let mask = (1 << inverted_index) - 1;
+        let mut result = Self {
+            evaluations: BTreeMap::new(),
+            num_of_vars: self.num_of_vars - 1,
+        };
+
+        for (&index, &eval) in &self.evaluations {
+            let reduced_index = (index >> (inverted_index + 1)) << inverted_index | (index & mask);
+
+            if index & (1 << inverted_index) == 0 {
+                result.insert_term(reduced_index, eval * (F::one() - value));
+            } else {
+                result.insert_term(reduced_index, eval * value);
+            }
+        }
+
+        result
+    }
+
+    pub fn evaluate(&self, values: &[F]) -> F {
+        if values.len() != self.num_of_vars {
+            panic!("Invalid number of values");
+        }
+
+        let mut result = F::zero();
+
+        for (&index, &eval) in &self.evaluations {
+            let mut term = eval;
+
+            for (i, value) in values.iter().enumerate() {
+                if index & (1 << (self.num_of_vars - i - 1)) == 0 {
+                    term *= F::one() - value;
+                } else {
+                    term *= value;
+                }
+            }
+
+            result += term;
+        }
+
+        result
+    }
+
+    pub fn scale(&self, value: F) -> Self {
+        let evaluations = self
+            .evaluations
+            .iter()
+            .map(|(&index, &eval)| (index, eval * value))
+            .collect();
+
+        Self::new(self.num_of_vars, evaluations)
+    }
+
+    /// Expands into the dense representation holding all 2^n evaluations.
+    pub fn to_dense(&self) -> MultilinearPoly<F> {
+        let mut evaluation = vec![F::zero(); 1 << self.num_of_vars];
+
+        for (&index, &eval) in &self.evaluations {
+            evaluation[index] = eval;
+        }
+
+        MultilinearPoly::new(evaluation)
+    }
+
+    /// Keeps only the non-zero evaluations of a dense polynomial.
+    pub fn from_dense(poly: &MultilinearPoly<F>) -> Self {
+        let evaluations = poly
+            .evaluation
+            .iter()
+            .enumerate()
+            .filter(|(_, eval)| !eval.is_zero())
+            .map(|(index, &eval)| (index, eval))
+            .collect();
+
+        Self::new(poly.num_of_vars, evaluations)
+    }
+}
+
+impl<F: PrimeField> Add for SparseMultilinearPoly<F> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        if self.num_of_vars != other.num_of_vars {
+            panic!("Mismatched number of variables");
+        }
+
+        let mut result = self;
+        for (index, eval) in other.evaluations {
+            result.insert_term(index, eval);
+        }
+
+        result
+    }
+}
+
+impl<F: PrimeField> From<SparseMultilinearPoly<F>> for MultilinearPoly<F> {
+    fn from(poly: SparseMultilinearPoly<F>) -> Self {
+        poly.to_dense()
+    }
+}
+
+impl<F: PrimeField> From<MultilinearPoly<F>> for SparseMultilinearPoly<F> {
+    fn from(poly: MultilinearPoly<F>) -> Self {
+        SparseMultilinearPoly::from_dense(&poly)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn test_setup() -> SparseMultilinearPoly<Fq> {
+        // Dense form: [0, 0, 0, 7, 0, 2, 0, 0]
+        SparseMultilinearPoly::new(3, vec![(3, Fq::from(7)), (5, Fq::from(2))])
+    }
+
+    #[test]
+    fn it_drops_zero_evaluations() {
+        let poly = SparseMultilinearPoly::new(
+            2,
+            vec![(0, Fq::from(0)), (1, Fq::from(4)), (1, -Fq::from(4))],
+        );
+
+        assert_eq!(poly.num_non_zero(), 0);
+    }
+
+    #[test]
+    fn it_matches_dense_evaluation() {
+        let sparse = test_setup();
+        let dense = sparse.to_dense();
+        let values = vec![Fq::from(5), Fq::from(2), Fq::from(9)];
+
+        assert_eq!(sparse.evaluate(&values), dense.evaluate(&values));
+    }
+
+    #[test]
+    fn it_matches_dense_partial_evaluation() {
+        let sparse = test_setup();
+        let dense = sparse.to_dense();
+        let value = Fq::from(3);
+
+        for bit in 0..3 {
+            assert_eq!(
+                sparse.partial_evaluate(bit, &value).to_dense(),
+                dense.partial_evaluate(bit, &value)
+            );
+        }
+    }
+
+    #[test]
+    fn it_adds_and_scales() {
+        let poly = test_setup();
+        let other = SparseMultilinearPoly::new(3, vec![(3, -Fq::from(7)), (6, Fq::from(1))]);
+
+        let sum = poly.clone() + other;
+        assert_eq!(
+            sum,
+            SparseMultilinearPoly::new(3, vec![(5, Fq::from(2)), (6, Fq::from(1))])
+        );
+
+        let scaled = poly.scale(Fq::from(3));
+        assert_eq!(scaled.evaluations.get(&3), Some(&Fq::from(21)));
+    }
+
+    #[test]
+    fn it_round_trips_through_dense_form() {
+        let dense = MultilinearPoly::new(vec![Fq::from(0), Fq::from(0), Fq::from(3), Fq::from(10)]);
+        let sparse = SparseMultilinearPoly::from(dense.clone());
+
+        assert_eq!(sparse.num_non_zero(), 2);
+        assert_eq!(MultilinearPoly::from(sparse), dense);
+    }
+}
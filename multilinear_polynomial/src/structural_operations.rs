@@ -0,0 +1,180 @@
+use crate::error::MultilinearError;
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+use ark_ff::PrimeField;
+
+impl<F: PrimeField> MultilinearPoly<F> {
+    /// Inserts `count` variables the polynomial does not depend on, starting at variable
+    /// `position`. Existing variables at or after `position` shift along by `count`.
+    pub fn add_dummy_variables(&self, position: usize, count: usize) -> Self {
+        if position > self.num_of_vars {
+            panic!(
+                "{}",
+                MultilinearError::InvalidVariable {
+                    variable: position,
+                    num_of_vars: self.num_of_vars,
+                }
+            );
+        }
+
+        let low_bits = self.num_of_vars - position;
+        let low_mask = (1 << low_bits) - 1;
+        let evaluation = (0..1 << (self.num_of_vars + count))
+            .map(|index: usize| {
+                let high = index >> (low_bits + count);
+                self.evaluation[high << low_bits | (index & low_mask)]
+            })
+            .collect();
+
+        Self::new(evaluation)
+    }
+
+    /// Combines polynomials in the same variables into one, selected by new leading variables:
+    /// the result at (s, x) is polys[s](x), and zero for selectors past the last polynomial.
+    pub fn concat(polys: &[Self]) -> Self {
+        let first = polys.first().expect("Cannot concatenate zero polynomials");
+        let mut evaluation =
+            Vec::with_capacity(polys.len().next_power_of_two() << first.num_of_vars);
+
+        for poly in polys {
+            if poly.num_of_vars != first.num_of_vars {
+                panic!(
+                    "{}",
+                    MultilinearError::MismatchedVariables {
+                        left: first.num_of_vars,
+                        right: poly.num_of_vars,
+                    }
+                );
+            }
+            evaluation.extend_from_slice(&poly.evaluation);
+        }
+
+        evaluation.resize(
+            polys.len().next_power_of_two() << first.num_of_vars,
+            F::zero(),
+        );
+
+        Self::new(evaluation)
+    }
+
+    /// Returns f(x)·g(y) over the disjoint variables (x, y), with `self`'s variables first.
+    pub fn tensor(&self, other: &Self) -> Self {
+        let evaluation = self
+            .evaluation
+            .iter()
+            .flat_map(|a| other.evaluation.iter().map(move |b| *a * b))
+            .collect();
+
+        Self::new(evaluation)
+    }
+
+    /// Reorders variables so that variable `i` of the result is variable `permutation[i]` of `self`.
+    /// Panics if `permutation` is not a permutation of 0..n.
+    pub fn permute_variables(&self, permutation: &[usize]) -> Self {
+        let n = self.num_of_vars;
+        let mut sorted = permutation.to_vec();
+        sorted.sort_unstable();
+        if sorted != (0..n).collect::<Vec<_>>() {
+            panic!("Invalid variable permutation");
+        }
+
+        let evaluation = (0..1 << n)
+            .map(|index: usize| {
+                let old_index = permutation
+                    .iter()
+                    .enumerate()
+                    .filter(|(new_variable, _)| index & (1 << (n - new_variable - 1)) != 0)
+                    .fold(0, |acc, (_, &old_variable)| {
+                        acc | 1 << (n - old_variable - 1)
+                    });
+                self.evaluation[old_index]
+            })
+            .collect();
+
+        Self::new(evaluation)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn test_setup() -> MultilinearPoly<Fq> {
+        MultilinearPoly::new(vec![
+            Fq::from(3),
+            Fq::from(1),
+            Fq::from(4),
+            Fq::from(1),
+            Fq::from(5),
+            Fq::from(9),
+            Fq::from(2),
+            Fq::from(6),
+        ])
+    }
+
+    fn point(values: &[u64]) -> Vec<Fq> {
+        values.iter().map(|v| Fq::from(*v)).collect()
+    }
+
+    #[test]
+    fn it_adds_dummy_variables() {
+        let polynomial = test_setup();
+        let extended = polynomial.add_dummy_variables(1, 2);
+
+        assert_eq!(extended.num_of_vars, 5);
+        assert_eq!(
+            extended.evaluate(&point(&[2, 10, 20, 3, 4])),
+            polynomial.evaluate(&point(&[2, 3, 4]))
+        );
+        assert_eq!(polynomial.add_dummy_variables(3, 0), polynomial);
+    }
+
+    #[test]
+    fn it_concatenates_with_selectors() {
+        let a = MultilinearPoly::new(point(&[1, 2]));
+        let b = MultilinearPoly::new(point(&[3, 4]));
+        let c = MultilinearPoly::new(point(&[5, 6]));
+
+        let combined = MultilinearPoly::concat(&[a, b.clone(), c]);
+
+        assert_eq!(combined.evaluation, point(&[1, 2, 3, 4, 5, 6, 0, 0]));
+        assert_eq!(
+            combined
+                .partial_evaluate(0, &Fq::from(0))
+                .partial_evaluate(0, &Fq::from(1)),
+            b
+        );
+    }
+
+    #[test]
+    fn it_takes_tensor_products() {
+        let f = MultilinearPoly::new(point(&[1, 2]));
+        let g = test_setup();
+        let product = f.tensor(&g);
+
+        assert_eq!(product.num_of_vars, 4);
+        assert_eq!(
+            product.evaluate(&point(&[7, 2, 3, 4])),
+            f.evaluate(&point(&[7])) * g.evaluate(&point(&[2, 3, 4]))
+        );
+    }
+
+    #[test]
+    fn it_permutes_variables() {
+        let polynomial = test_setup();
+        let permuted = polynomial.permute_variables(&[2, 0, 1]);
+
+        // Result variable i reads variable permutation[i] of the original.
+        assert_eq!(
+            permuted.evaluate(&point(&[4, 2, 3])),
+            polynomial.evaluate(&point(&[2, 3, 4]))
+        );
+        assert_eq!(polynomial.permute_variables(&[0, 1, 2]), polynomial);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid variable permutation")]
+    fn it_rejects_invalid_permutations() {
+        let _ = test_setup().permute_variables(&[0, 0, 1]);
+    }
+}
@@ -0,0 +1,226 @@
+use crate::eq_polynomial::eq_evaluations;
+use crate::error::MultilinearError;
+use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+use ark_ff::PrimeField;
+
+impl<F: PrimeField> MultilinearPoly<F> {
+    /// Fixes every variable in `assignments`, given as (variable, value) pairs in any order,
+    /// in a single pass over the table. The remaining variables keep their relative order.
+    pub fn fix_variables(&self, assignments: &[(usize, F)]) -> Self {
+        self.try_fix_variables(assignments)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    /// Like `fix_variables`, reporting an error for an out-of-range or repeated variable.
+    pub fn try_fix_variables(&self, assignments: &[(usize, F)]) -> Result<Self, MultilinearError> {
+        let mut sorted = assignments.to_vec();
+        sorted.sort_by_key(|(variable, _)| *variable);
+
+        for (i, (variable, _)) in sorted.iter().enumerate() {
+            if *variable >= self.num_of_vars {
+                return Err(MultilinearError::InvalidVariable {
+                    variable: *variable,
+                    num_of_vars: self.num_of_vars,
+                });
+            }
+            if i > 0 && sorted[i - 1].0 == *variable {
+                return Err(MultilinearError::DuplicateVariable(*variable));
+            }
+        }
+
+        let is_fixed = |variable: usize| sorted.iter().any(|(fixed, _)| *fixed == variable);
+        let fixed_bits: Vec<usize> = sorted
+            .iter()
+            .map(|(variable, _)| self.bit(*variable))
+            .collect();
+        let free_bits: Vec<usize> = (0..self.num_of_vars)
+            .filter(|variable| !is_fixed(*variable))
+            .map(|variable| self.bit(variable))
+            .collect();
+
+        // Every table index splits into a fixed part and a free part; precomputing both
+        // offset tables turns the fold into result[j] = Σ_b eq(values, b)·f[free[j] | fixed[b]].
+        let values: Vec<F> = sorted.iter().map(|(_, value)| *value).collect();
+        let weights = eq_evaluations(&values);
+        let fixed_offsets = deposit_all(&fixed_bits);
+
+        let evaluation = deposit_all(&free_bits)
+            .into_iter()
+            .map(|free| {
+                weights
+                    .iter()
+                    .zip(fixed_offsets.iter())
+                    .map(|(weight, fixed)| *weight * self.evaluation[free | fixed])
+                    .sum()
+            })
+            .collect();
+
+        Self::try_new(evaluation)
+    }
+
+    /// Fixes the leading variables 0..values.len() to `values` in a single pass.
+    pub fn fix_prefix(&self, values: &[F]) -> Self {
+        let num_remaining = self.checked_remaining(values.len());
+        let weights = eq_evaluations(values);
+        let mut evaluation = vec![F::zero(); 1 << num_remaining];
+
+        for (row, weight) in self.evaluation.chunks_exact(evaluation.len()).zip(weights) {
+            for (acc, value) in evaluation.iter_mut().zip(row.iter()) {
+                *acc += weight * value;
+            }
+        }
+
+        Self::new(evaluation)
+    }
+
+    /// Fixes the trailing variables to `values` in a single pass: the last variable takes the
+    /// last value.
+    pub fn fix_suffix(&self, values: &[F]) -> Self {
+        self.checked_remaining(values.len());
+        let weights = eq_evaluations(values);
+
+        let evaluation = self
+            .evaluation
+            .chunks_exact(weights.len())
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .zip(weights.iter())
+                    .map(|(value, weight)| *value * weight)
+                    .sum()
+            })
+            .collect();
+
+        Self::new(evaluation)
+    }
+
+    /// The table index bit holding `variable`; variable 0 is the most significant.
+    fn bit(&self, variable: usize) -> usize {
+        self.num_of_vars - 1 - variable
+    }
+
+    fn checked_remaining(&self, num_fixed: usize) -> usize {
+        if num_fixed > self.num_of_vars {
+            panic!(
+                "{}",
+                MultilinearError::InvalidNumberOfValues {
+                    expected: self.num_of_vars,
+                    found: num_fixed,
+                }
+            );
+        }
+
+        self.num_of_vars - num_fixed
+    }
+}
+
+/// Lists, for every k-bit value in order, the index with its bits placed at `bits`
+/// (the first entry of `bits` receiving the most significant bit).
+fn deposit_all(bits: &[usize]) -> Vec<usize> {
+    let mut offsets = vec![0];
+
+    for bit in bits {
+        offsets = offsets
+            .iter()
+            .flat_map(|offset| [*offset, *offset | 1 << bit])
+            .collect();
+    }
+
+    offsets
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    fn test_polynomial() -> MultilinearPoly<Fq> {
+        MultilinearPoly::new((0..16u64).map(|i| Fq::from(i * i + 3 * i + 1)).collect())
+    }
+
+    fn sequential(
+        polynomial: &MultilinearPoly<Fq>,
+        assignments: &[(usize, Fq)],
+    ) -> MultilinearPoly<Fq> {
+        // Fix from the highest variable down so earlier indices stay valid.
+        let mut sorted = assignments.to_vec();
+        sorted.sort_by_key(|(variable, _)| std::cmp::Reverse(*variable));
+
+        sorted
+            .iter()
+            .fold(polynomial.clone(), |poly, (variable, value)| {
+                poly.partial_evaluate(*variable, value)
+            })
+    }
+
+    #[test]
+    fn it_fixes_arbitrary_variables() {
+        let polynomial = test_polynomial();
+        let cases = vec![
+            vec![],
+            vec![(2, Fq::from(7))],
+            vec![(3, Fq::from(5)), (0, Fq::from(9))],
+            vec![(1, Fq::from(4)), (3, Fq::from(2)), (2, Fq::from(11))],
+            vec![
+                (0, Fq::from(1)),
+                (1, Fq::from(2)),
+                (2, Fq::from(3)),
+                (3, Fq::from(4)),
+            ],
+        ];
+
+        for assignments in cases {
+            assert_eq!(
+                polynomial.fix_variables(&assignments),
+                sequential(&polynomial, &assignments)
+            );
+        }
+    }
+
+    #[test]
+    fn it_fixes_prefix_and_suffix() {
+        let polynomial = test_polynomial();
+        let values = [Fq::from(6), Fq::from(13)];
+
+        assert_eq!(
+            polynomial.fix_prefix(&values),
+            polynomial
+                .partial_evaluate(0, &values[0])
+                .partial_evaluate(0, &values[1])
+        );
+        assert_eq!(
+            polynomial.fix_suffix(&values),
+            polynomial.fix_variables(&[(2, values[0]), (3, values[1])])
+        );
+
+        let point = [Fq::from(2), Fq::from(3), Fq::from(5), Fq::from(8)];
+        assert_eq!(
+            polynomial.fix_suffix(&point[2..]).evaluate(&point[..2]),
+            polynomial.evaluate(&point)
+        );
+        assert_eq!(polynomial.fix_prefix(&[]), polynomial);
+    }
+
+    #[test]
+    fn it_reports_invalid_assignments() {
+        let polynomial = test_polynomial();
+
+        assert_eq!(
+            polynomial.try_fix_variables(&[(4, Fq::from(1))]),
+            Err(MultilinearError::InvalidVariable {
+                variable: 4,
+                num_of_vars: 4
+            })
+        );
+        assert_eq!(
+            polynomial.try_fix_variables(&[(1, Fq::from(1)), (1, Fq::from(2))]),
+            Err(MultilinearError::DuplicateVariable(1))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid number of values")]
+    fn it_panics_on_long_suffix() {
+        test_polynomial().fix_suffix(&[Fq::from(1); 5]);
+    }
+}
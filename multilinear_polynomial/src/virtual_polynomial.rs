@@ -0,0 +1,238 @@
+use crate::multilinear_view::SharedMultilinear;
+use ark_ff::{BigInteger, PrimeField};
+
+/// A sum of products of multilinear polynomials: Σ_k c_k · Π_j W_{k,j}(x).
+///
+/// Unlike `MultilinearPoly * MultilinearPoly`, which multiplies evaluations pointwise and
+/// is only correct on the hypercube, this keeps the factors separate so the product has
+/// its true degree and evaluates correctly at any point. Factors are `SharedMultilinear`
+/// views, so the same table can appear in several products, or in other protocols, without
+/// being copied.
+#[derive(Clone, Debug)]
+pub struct VirtualPolynomial<F: PrimeField> {
+    pub num_of_vars: usize,
+    pub products: Vec<(F, Vec<usize>)>, // coefficient, indices into `multilinears`
+    pub multilinears: Vec<SharedMultilinear<F>>,
+}
+
+impl<F: PrimeField> VirtualPolynomial<F> {
+    pub fn new(num_of_vars: usize) -> Self {
+        Self {
+            num_of_vars,
+            products: Vec::new(),
+            multilinears: Vec::new(),
+        }
+    }
+
+    /// Returns the number of variables in the virtual polynomial.
+    pub fn number_of_variables(&self) -> usize {
+        self.num_of_vars
+    }
+
+    /// Adds `coefficient · Π factors`. Factors sharing the storage of one already present
+    /// are reused. Panics if a factor has the wrong number of variables.
+    pub fn add_product(&mut self, coefficient: F, factors: Vec<SharedMultilinear<F>>) {
+        let mut indices = Vec::with_capacity(factors.len());
+
+        for factor in factors {
+            if factor.number_of_variables() != self.num_of_vars {
+                panic!("Mismatched number of variables");
+            }
+
+            let index = match self
+                .multilinears
+                .iter()
+                .position(|existing| existing.shares_storage_with(&factor))
+            {
+                Some(index) => index,
+                None => {
+                    self.multilinears.push(factor);
+                    self.multilinears.len() - 1
+                }
+            };
+            indices.push(index);
+        }
+
+        self.products.push((coefficient, indices));
+    }
+
+    /// Returns the highest degree in any single variable, i.e. the largest product size.
+    pub fn max_degree(&self) -> usize {
+        self.products
+            .iter()
+            .map(|(_, indices)| indices.len())
+            .max()
+            .unwrap_or(0)
+    }
+
+    pub fn evaluate(&self, values: &[F]) -> F {
+        let evaluations: Vec<F> = self
+            .multilinears
+            .iter()
+            .map(|multilinear| multilinear.evaluate(values))
+            .collect();
+
+        self.products
+            .iter()
+            .map(|(coefficient, indices)| {
+                *coefficient * indices.iter().map(|&i| evaluations[i]).product::<F>()
+            })
+            .sum()
+    }
+
+    /// Fixes variable 0 of every factor to `value`.
+    pub fn partial_evaluate(&self, value: &F) -> Self {
+        Self {
+            num_of_vars: self.num_of_vars - 1,
+            products: self.products.clone(),
+            multilinears: self
+                .multilinears
+                .iter()
+                .map(|multilinear| multilinear.partial_evaluate(0, value).into_shared())
+                .collect(),
+        }
+    }
+
+    /// Sums the polynomial over every point of the hypercube.
+    pub fn sum_over_hypercube(&self) -> F {
+        (0..1 << self.num_of_vars)
+            .map(|index| {
+                self.products
+                    .iter()
+                    .map(|(coefficient, indices)| {
+                        *coefficient
+                            * indices
+                                .iter()
+                                .map(|&i| self.multilinears[i].evaluations()[index])
+                                .product::<F>()
+                    })
+                    .sum::<F>()
+            })
+            .sum()
+    }
+
+    /// Returns the sumcheck round polynomial for variable 0, as its evaluations at
+    /// 0, 1, ..., max(max_degree, 1). Every other variable is summed over the hypercube.
+    pub fn round_evaluations(&self) -> Vec<F> {
+        let degree = self.max_degree().max(1);
+        let half = 1 << (self.num_of_vars - 1);
+        let mut sums = vec![F::zero(); degree + 1];
+
+        for (coefficient, indices) in &self.products {
+            for pair in 0..half {
+                let mut values: Vec<F> = indices
+                    .iter()
+                    .map(|&i| self.multilinears[i].evaluations()[pair])
+                    .collect();
+                let steps: Vec<F> = indices
+                    .iter()
+                    .map(|&i| {
+                        let evaluations = self.multilinears[i].evaluations();
+                        evaluations[pair + half] - evaluations[pair]
+                    })
+                    .collect();
+
+                for sum in sums.iter_mut() {
+                    *sum += *coefficient * values.iter().product::<F>();
+
+                    for (value, step) in values.iter_mut().zip(steps.iter()) {
+                        *value += step;
+                    }
+                }
+            }
+        }
+
+        sums
+    }
+
+    pub fn convert_to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::new();
+
+        for (coefficient, indices) in &self.products {
+            bytes.extend(coefficient.into_bigint().to_bytes_be());
+            for index in indices {
+                bytes.extend((*index as u64).to_be_bytes());
+            }
+        }
+
+        for multilinear in &self.multilinears {
+            bytes.extend(multilinear.convert_to_bytes());
+        }
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::multilinear_polynomial_evaluation::MultilinearPoly;
+    use ark_bn254::Fq;
+
+    fn test_setup() -> (
+        VirtualPolynomial<Fq>,
+        SharedMultilinear<Fq>,
+        SharedMultilinear<Fq>,
+    ) {
+        let a = MultilinearPoly::new(vec![Fq::from(1), Fq::from(2), Fq::from(3), Fq::from(4)])
+            .into_shared();
+        let b = MultilinearPoly::new(vec![Fq::from(5), Fq::from(0), Fq::from(7), Fq::from(2)])
+            .into_shared();
+
+        // 2·a·b + 3·a
+        let mut polynomial = VirtualPolynomial::new(2);
+        polynomial.add_product(Fq::from(2), vec![a.clone(), b.clone()]);
+        polynomial.add_product(Fq::from(3), vec![a.clone()]);
+
+        (polynomial, a, b)
+    }
+
+    #[test]
+    fn it_shares_factors() {
+        let (mut polynomial, a, _) = test_setup();
+
+        assert_eq!(polynomial.multilinears.len(), 2);
+        assert_eq!(polynomial.max_degree(), 2);
+
+        // An equal table in separate storage is a new factor, not a reuse of `a`
+        polynomial.add_product(Fq::from(1), vec![a.to_multilinear().into_shared()]);
+        assert_eq!(polynomial.multilinears.len(), 3);
+    }
+
+    #[test]
+    fn it_evaluates_off_the_hypercube() {
+        let (polynomial, a, b) = test_setup();
+        let point = vec![Fq::from(5), Fq::from(9)];
+
+        let a_eval = a.evaluate(&point);
+        let b_eval = b.evaluate(&point);
+        let expected = Fq::from(2) * a_eval * b_eval + Fq::from(3) * a_eval;
+
+        assert_eq!(polynomial.evaluate(&point), expected);
+
+        // The pointwise product only agrees on the hypercube.
+        let pointwise = a.to_multilinear() * b.to_multilinear();
+        assert_ne!(pointwise.evaluate(&point), a_eval * b_eval);
+    }
+
+    #[test]
+    fn it_sums_over_the_hypercube() {
+        let (polynomial, _, _) = test_setup();
+
+        // 2·(5 + 0 + 21 + 8) + 3·(1 + 2 + 3 + 4)
+        assert_eq!(polynomial.sum_over_hypercube(), Fq::from(98));
+    }
+
+    #[test]
+    fn it_computes_round_evaluations() {
+        let (polynomial, _, _) = test_setup();
+        let round = polynomial.round_evaluations();
+
+        assert_eq!(round.len(), 3);
+        assert_eq!(round[0] + round[1], polynomial.sum_over_hypercube());
+
+        // g(2) is the sum of the polynomial with variable 0 fixed to 2.
+        let fixed = polynomial.partial_evaluate(&Fq::from(2));
+        assert_eq!(round[2], fixed.sum_over_hypercube());
+    }
+}
@@ -0,0 +1,103 @@
+use ark_ff::{batch_inversion, PrimeField};
+
+/// Precomputed barycentric weights w_j = 1 / Π_{k≠j} (x_j − x_k) for a fixed set of points.
+///
+/// Once built, the interpolant through any values on these points can be evaluated in O(n)
+/// without ever forming its coefficients:
+/// p(z) = L(z) · Σ_j w_j · y_j / (z − x_j), where L(z) = Π_k (z − x_k).
+#[derive(Clone, Debug, PartialEq)]
+pub struct BarycentricWeights<F: PrimeField> {
+    pub points: Vec<F>,
+    pub weights: Vec<F>,
+}
+
+impl<F: PrimeField> BarycentricWeights<F> {
+    /// Computes the weights in O(n²) multiplications and a single inversion.
+    /// Panics if the points are not distinct.
+    pub fn new(points: &[F]) -> Self {
+        let mut weights: Vec<F> = points
+            .iter()
+            .enumerate()
+            .map(|(j, x_j)| {
+                points
+                    .iter()
+                    .enumerate()
+                    .filter(|(k, _)| *k != j)
+                    .map(|(_, x_k)| *x_j - x_k)
+                    .product()
+            })
+            .collect();
+
+        if weights.iter().any(|weight| weight.is_zero()) {
+            panic!("Interpolation points must be distinct");
+        }
+        batch_inversion(&mut weights);
+
+        Self {
+            points: points.to_vec(),
+            weights,
+        }
+    }
+
+    /// Weights for the points 0, 1, ..., n − 1, in O(n) using the closed form
+    /// w_j = (−1)^(n−1−j) / (j! · (n−1−j)!).
+    pub fn for_consecutive_points(n: usize) -> Self {
+        let mut factorials = vec![F::one(); n.max(1)];
+        for i in 1..n {
+            factorials[i] = factorials[i - 1] * F::from(i as u64);
+        }
+
+        let mut weights: Vec<F> = (0..n)
+            .map(|j| {
+                let denominator = factorials[j] * factorials[n - 1 - j];
+                if (n - 1 - j) % 2 == 1 {
+                    -denominator
+                } else {
+                    denominator
+                }
+            })
+            .collect();
+        batch_inversion(&mut weights);
+
+        Self {
+            points: (0..n as u64).map(F::from).collect(),
+            weights,
+        }
+    }
+
+    /// Evaluates at `at` the unique polynomial of degree < n taking `values` on the points,
+    /// in O(n) multiplications and one inversion. Panics if the lengths differ.
+    pub fn evaluate(&self, values: &[F], at: F) -> F {
+        if values.len() != self.points.len() {
+            panic!("Invalid number of values");
+        }
+
+        if let Some(j) = self.points.iter().position(|x| *x == at) {
+            return values[j];
+        }
+
+        let mut differences: Vec<F> = self.points.iter().map(|x| at - x).collect();
+        let vanishing: F = differences.iter().product();
+        batch_inversion(&mut differences);
+
+        let sum: F = self
+            .weights
+            .iter()
+            .zip(values.iter())
+            .zip(differences.iter())
+            .map(|((weight, value), inverse)| *weight * value * inverse)
+            .sum();
+
+        vanishing * sum
+    }
+}
+
+/// Evaluates at `at` the polynomial through (x_values[i], y_values[i]) without building its
+/// coefficients, for a one-off evaluation.
+///
+/// This builds the weights from scratch, so each call costs O(n²). Callers evaluating more than
+/// once over the same points should keep a `BarycentricWeights` and call its `evaluate`, which
+/// costs O(n) per evaluation.
+pub fn evaluate_from_evaluations<F: PrimeField>(x_values: &[F], y_values: &[F], at: F) -> F {
+    BarycentricWeights::new(x_values).evaluate(y_values, at)
+}
@@ -0,0 +1,165 @@
+use crate::operations::{multiply_polynomials, FFT_MULTIPLICATION_THRESHOLD};
+use crate::polynomial::DenseUnivariatePoly;
+use crate::univariate::{from_trimmed, trim_coefficients};
+use ark_ff::PrimeField;
+
+/// Divides `dividend` by `divisor` in coefficient form, returning (quotient, remainder) with
+/// deg(remainder) < deg(divisor). Both results are trimmed, so zero is the empty vector.
+///
+/// Monic divisors of the form x − a or x^n − c take O(deg) fast paths. Large general
+/// divisions go through a Newton-iteration inverse on top of FFT multiplication; small ones
+/// use schoolbook long division. Panics if `divisor` is the zero polynomial.
+pub fn div_rem<F: PrimeField>(dividend: &[F], divisor: &[F]) -> (Vec<F>, Vec<F>) {
+    let divisor = trim_coefficients(divisor.to_vec());
+    let Some((&leading, rest)) = divisor.split_last() else {
+        panic!("Division by zero polynomial");
+    };
+
+    let n = rest.len();
+    if leading.is_one() && n > 0 && rest[1..].iter().all(|coeff| coeff.is_zero()) {
+        if n == 1 {
+            let (quotient, remainder) = divide_by_linear(dividend, -rest[0]);
+            return (quotient, trim_coefficients(vec![remainder]));
+        }
+        return divide_by_vanishing(dividend, n, -rest[0]);
+    }
+
+    let quotient_len = trim_coefficients(dividend.to_vec()).len().saturating_sub(n);
+    if quotient_len.min(divisor.len()) >= FFT_MULTIPLICATION_THRESHOLD {
+        return newton_division(dividend, &divisor);
+    }
+
+    long_division(dividend, &divisor)
+}
+
+/// Synthetic division by (x − a): returns the quotient and the remainder f(a).
+pub fn divide_by_linear<F: PrimeField>(dividend: &[F], a: F) -> (Vec<F>, F) {
+    let dividend = trim_coefficients(dividend.to_vec());
+    let Some((&leading, rest)) = dividend.split_last() else {
+        return (vec![], F::zero());
+    };
+
+    let mut quotient = vec![F::zero(); rest.len()];
+    let mut carry = leading;
+
+    for (i, coeff) in rest.iter().enumerate().rev() {
+        quotient[i] = carry;
+        carry = *coeff + a * carry;
+    }
+
+    (trim_coefficients(quotient), carry)
+}
+
+/// Divides by x^n − c in O(deg) by folding each coefficient at degree i ≥ n onto degree
+/// i − n: x^i = x^(i−n)·(x^n − c) + c·x^(i−n).
+pub fn divide_by_vanishing<F: PrimeField>(dividend: &[F], n: usize, c: F) -> (Vec<F>, Vec<F>) {
+    if n == 0 {
+        panic!("Division by a constant vanishing polynomial");
+    }
+
+    let mut remainder = trim_coefficients(dividend.to_vec());
+    if remainder.len() <= n {
+        return (vec![], remainder);
+    }
+
+    let mut quotient = vec![F::zero(); remainder.len() - n];
+
+    for i in (n..remainder.len()).rev() {
+        let coeff = remainder[i];
+        quotient[i - n] = coeff;
+        remainder[i - n] += c * coeff;
+    }
+
+    remainder.truncate(n);
+    (trim_coefficients(quotient), trim_coefficients(remainder))
+}
+
+fn long_division<F: PrimeField>(dividend: &[F], divisor: &[F]) -> (Vec<F>, Vec<F>) {
+    let mut remainder = trim_coefficients(dividend.to_vec());
+    let divisor_degree = divisor.len() - 1;
+    if remainder.len() <= divisor_degree {
+        return (vec![], remainder);
+    }
+
+    let leading_inv = divisor[divisor_degree]
+        .inverse()
+        .expect("leading coefficient is non-zero");
+    let mut quotient = vec![F::zero(); remainder.len() - divisor_degree];
+
+    for shift in (0..quotient.len()).rev() {
+        let factor = remainder[shift + divisor_degree] * leading_inv;
+        quotient[shift] = factor;
+
+        for (j, coeff) in divisor.iter().enumerate() {
+            remainder[shift + j] -= factor * coeff;
+        }
+    }
+
+    remainder.truncate(divisor_degree);
+    (trim_coefficients(quotient), trim_coefficients(remainder))
+}
+
+/// Division via reversal: rev(q) = rev(a) · rev(b)⁻¹ mod x^(deg a − deg b + 1), where the
+/// power-series inverse is found by Newton iteration, so the cost is a few multiplications.
+fn newton_division<F: PrimeField>(dividend: &[F], divisor: &[F]) -> (Vec<F>, Vec<F>) {
+    let dividend = trim_coefficients(dividend.to_vec());
+    let divisor_degree = divisor.len() - 1;
+    if dividend.len() <= divisor_degree {
+        return (vec![], dividend);
+    }
+
+    let quotient_len = dividend.len() - divisor_degree;
+    let reversed_dividend: Vec<F> = dividend.iter().rev().take(quotient_len).copied().collect();
+    let reversed_divisor: Vec<F> = divisor.iter().rev().copied().collect();
+
+    let inverse = power_series_inverse(&reversed_divisor, quotient_len);
+    let mut quotient = multiply_polynomials(reversed_dividend, inverse);
+    quotient.resize(quotient_len, F::zero());
+    quotient.reverse();
+
+    let product = multiply_polynomials(quotient.clone(), divisor.to_vec());
+    let remainder: Vec<F> = dividend
+        .iter()
+        .zip(product.iter())
+        .take(divisor_degree)
+        .map(|(a, b)| *a - b)
+        .collect();
+
+    (trim_coefficients(quotient), trim_coefficients(remainder))
+}
+
+/// Returns g with f·g ≡ 1 mod x^precision, doubling the precision each step via
+/// g ← g·(2 − f·g). Requires f(0) ≠ 0.
+fn power_series_inverse<F: PrimeField>(f: &[F], precision: usize) -> Vec<F> {
+    let mut inverse = vec![f[0].inverse().expect("constant term is non-zero")];
+    let mut current = 1;
+
+    while current < precision {
+        current = (2 * current).min(precision);
+
+        let truncated: Vec<F> = f.iter().take(current).copied().collect();
+        let mut correction = multiply_polynomials(truncated, inverse.clone());
+        correction.resize(current, F::zero());
+        correction.iter_mut().for_each(|coeff| *coeff = -*coeff);
+        correction[0] += F::from(2u64);
+
+        inverse = multiply_polynomials(inverse, correction);
+        inverse.resize(current, F::zero());
+    }
+
+    inverse
+}
+
+impl<F: PrimeField> DenseUnivariatePoly<F> {
+    /// Returns the quotient by (x − a) and the remainder f(a).
+    pub fn div_by_linear(&self, a: F) -> (Self, F) {
+        let (quotient, remainder) = divide_by_linear(&self.coefficients, a);
+        (from_trimmed(quotient), remainder)
+    }
+
+    /// Returns (quotient, remainder) of division by x^n − c.
+    pub fn div_by_vanishing(&self, n: usize, c: F) -> (Self, Self) {
+        let (quotient, remainder) = divide_by_vanishing(&self.coefficients, n, c);
+        (from_trimmed(quotient), from_trimmed(remainder))
+    }
+}
@@ -0,0 +1,159 @@
+use crate::polynomial::DenseUnivariatePoly;
+use ark_ff::PrimeField;
+
+/// The multiplicative subgroup {1, ω, ω², ..., ω^(n-1)} of order n = 2^k, over which
+/// polynomials move between coefficient and evaluation form in O(n log n) via the NTT.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Radix2Domain<F: PrimeField> {
+    pub size: usize,
+    pub log_size: u32,
+    /// A primitive n-th root of unity ω.
+    pub generator: F,
+    pub generator_inv: F,
+    pub size_inv: F,
+}
+
+impl<F: PrimeField> Radix2Domain<F> {
+    /// Returns the smallest domain holding at least `size` points, or `None` if that exceeds
+    /// the field's two-adicity (2^28 for BN254 Fr, 2^32 for BLS12-381 Fr).
+    pub fn new(size: usize) -> Option<Self> {
+        let size = size.max(1).next_power_of_two();
+        let generator = F::get_root_of_unity(size as u64)?;
+
+        Some(Self {
+            size,
+            log_size: size.trailing_zeros(),
+            generator,
+            generator_inv: generator.inverse()?,
+            size_inv: F::from(size as u64).inverse()?,
+        })
+    }
+
+    /// Lists ω^0, ω^1, ..., ω^(n-1).
+    pub fn elements(&self) -> Vec<F> {
+        powers(self.generator, self.size)
+    }
+
+    /// Evaluates the polynomial with `coefficients` at every domain element.
+    /// Panics if there are more coefficients than domain points.
+    pub fn fft(&self, coefficients: &[F]) -> Vec<F> {
+        let mut values = self.padded(coefficients);
+        ntt(&mut values, self.generator);
+        values
+    }
+
+    /// Recovers the coefficients of the unique polynomial of degree < n through `evaluations`.
+    pub fn ifft(&self, evaluations: &[F]) -> Vec<F> {
+        let mut values = self.padded(evaluations);
+        ntt(&mut values, self.generator_inv);
+        values.iter_mut().for_each(|value| *value *= self.size_inv);
+        values
+    }
+
+    /// Evaluates over the coset offset·⟨ω⟩, which avoids dividing by zero when the
+    /// vanishing polynomial x^n − 1 of the domain itself appears in a quotient.
+    pub fn coset_fft(&self, coefficients: &[F], offset: F) -> Vec<F> {
+        let scaled: Vec<F> = coefficients
+            .iter()
+            .zip(powers(offset, coefficients.len()))
+            .map(|(coeff, power)| *coeff * power)
+            .collect();
+
+        self.fft(&scaled)
+    }
+
+    /// Inverse of `coset_fft` for the same `offset`.
+    pub fn coset_ifft(&self, evaluations: &[F], offset: F) -> Vec<F> {
+        let offset_inv = offset.inverse().expect("coset offset must be non-zero");
+
+        self.ifft(evaluations)
+            .into_iter()
+            .zip(powers(offset_inv, self.size))
+            .map(|(coeff, power)| coeff * power)
+            .collect()
+    }
+
+    /// Interpolates evaluations over the domain into coefficient form.
+    pub fn interpolate(&self, evaluations: &[F]) -> DenseUnivariatePoly<F> {
+        DenseUnivariatePoly::new(self.ifft(evaluations))
+    }
+
+    fn padded(&self, values: &[F]) -> Vec<F> {
+        if values.len() > self.size {
+            panic!("Too many values for the domain");
+        }
+
+        let mut padded = values.to_vec();
+        padded.resize(self.size, F::zero());
+        padded
+    }
+}
+
+/// Multiplies two polynomials in coefficient form by evaluating both over a domain large
+/// enough for the product, multiplying pointwise and interpolating back.
+pub fn fft_multiply<F: PrimeField>(left: &[F], right: &[F]) -> Vec<F> {
+    if left.is_empty() || right.is_empty() {
+        return vec![];
+    }
+
+    let product_len = left.len() + right.len() - 1;
+    let domain = Radix2Domain::new(product_len).expect("product exceeds the field's two-adicity");
+
+    let left_evals = domain.fft(left);
+    let right_evals = domain.fft(right);
+    let product: Vec<F> = left_evals
+        .iter()
+        .zip(right_evals.iter())
+        .map(|(a, b)| *a * b)
+        .collect();
+
+    let mut coefficients = domain.ifft(&product);
+    coefficients.truncate(product_len);
+    coefficients
+}
+
+fn powers<F: PrimeField>(base: F, count: usize) -> Vec<F> {
+    let mut result = Vec::with_capacity(count);
+    let mut current = F::one();
+
+    for _ in 0..count {
+        result.push(current);
+        current *= base;
+    }
+
+    result
+}
+
+/// In-place iterative Cooley–Tukey NTT; `root` must be a primitive root of unity of order
+/// values.len(), itself a power of 2.
+fn ntt<F: PrimeField>(values: &mut [F], root: F) {
+    let n = values.len();
+    if n <= 1 {
+        return;
+    }
+    let log_n = n.trailing_zeros();
+
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - log_n);
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let mut half = 1;
+    while half < n {
+        let step = root.pow([(n / (2 * half)) as u64]);
+        let twiddles = powers(step, half);
+
+        for chunk in values.chunks_exact_mut(2 * half) {
+            let (low, high) = chunk.split_at_mut(half);
+            for ((a, b), twiddle) in low.iter_mut().zip(high.iter_mut()).zip(twiddles.iter()) {
+                let t = *b * twiddle;
+                *b = *a - t;
+                *a += t;
+            }
+        }
+
+        half *= 2;
+    }
+}
@@ -0,0 +1,118 @@
+use crate::division::div_rem;
+use crate::operations::{add_polynomials, multiply_polynomials, scalar_mul};
+use crate::polynomial::DenseUnivariatePoly;
+use crate::univariate::{from_trimmed, trim_coefficients};
+use ark_ff::PrimeField;
+
+/// Returns the monic greatest common divisor of `left` and `right`; empty if both are zero.
+pub fn gcd<F: PrimeField>(left: &[F], right: &[F]) -> Vec<F> {
+    xgcd(left, right).0
+}
+
+/// Extended Euclidean algorithm: returns (g, s, t) with s·left + t·right = g, where g is the
+/// monic gcd. All three are trimmed, so g is empty only when both inputs are zero.
+pub fn xgcd<F: PrimeField>(left: &[F], right: &[F]) -> (Vec<F>, Vec<F>, Vec<F>) {
+    let mut previous = (trim_coefficients(left.to_vec()), vec![F::one()], vec![]);
+    let mut current = (trim_coefficients(right.to_vec()), vec![], vec![F::one()]);
+
+    while !current.0.is_empty() {
+        let next = euclid_step(&previous, &current);
+        previous = std::mem::replace(&mut current, next);
+    }
+
+    let (gcd, s, t) = previous;
+    let Some(leading) = gcd.last() else {
+        return (vec![], vec![], vec![]);
+    };
+    let leading_inv = leading.inverse().expect("leading coefficient is non-zero");
+
+    (
+        scalar_mul(leading_inv, gcd),
+        scalar_mul(leading_inv, s),
+        scalar_mul(leading_inv, t),
+    )
+}
+
+/// Runs the extended Euclidean algorithm on (left, right) only until the remainder has at
+/// most `bound` coefficients, returning that (r, s, t) with s·left + t·right = r, unnormalized.
+/// If `right` already has at most `bound` coefficients, no step is taken.
+///
+/// This is the half-way point used by rational reconstruction and Berlekamp–Welch decoding:
+/// with left = M and right = u, it yields r ≡ t·u mod M with deg r < bound and deg t small.
+pub fn xgcd_with_bound<F: PrimeField>(
+    left: &[F],
+    right: &[F],
+    bound: usize,
+) -> (Vec<F>, Vec<F>, Vec<F>) {
+    let mut previous = (trim_coefficients(left.to_vec()), vec![F::one()], vec![]);
+    let mut current = (trim_coefficients(right.to_vec()), vec![], vec![F::one()]);
+
+    while current.0.len() > bound {
+        let next = euclid_step(&previous, &current);
+        previous = std::mem::replace(&mut current, next);
+    }
+
+    current
+}
+
+/// Returns the inverse of `poly` modulo `modulus`, reduced below deg(modulus), or `None` if
+/// they share a common factor. Panics if `modulus` is the zero polynomial.
+pub fn inverse_mod<F: PrimeField>(poly: &[F], modulus: &[F]) -> Option<Vec<F>> {
+    let modulus = trim_coefficients(modulus.to_vec());
+    if modulus.is_empty() {
+        panic!("Modulus must be non-zero");
+    }
+
+    let (gcd, s, _) = xgcd(poly, &modulus);
+    if gcd != [F::one()] {
+        return None;
+    }
+
+    Some(div_rem(&s, &modulus).1)
+}
+
+type Row<F> = (Vec<F>, Vec<F>, Vec<F>);
+
+// One division step: (r, s, t)_{i+1} = (r, s, t)_{i−1} − q·(r, s, t)_i with q = r_{i−1} / r_i.
+fn euclid_step<F: PrimeField>(previous: &Row<F>, current: &Row<F>) -> Row<F> {
+    let (quotient, remainder) = div_rem(&previous.0, &current.0);
+
+    (
+        remainder,
+        subtract_product(&previous.1, &quotient, &current.1),
+        subtract_product(&previous.2, &quotient, &current.2),
+    )
+}
+
+// Computes a − q·b, treating empty vectors as zero.
+fn subtract_product<F: PrimeField>(a: &[F], q: &[F], b: &[F]) -> Vec<F> {
+    if q.is_empty() || b.is_empty() {
+        return a.to_vec();
+    }
+
+    let product = multiply_polynomials(q.to_vec(), b.to_vec());
+    trim_coefficients(add_polynomials(a.to_vec(), scalar_mul(-F::one(), product)))
+}
+
+impl<F: PrimeField> DenseUnivariatePoly<F> {
+    /// Returns the monic gcd of `self` and `other`.
+    pub fn gcd(&self, other: &Self) -> Self {
+        from_trimmed(gcd(&self.coefficients, &other.coefficients))
+    }
+
+    /// Returns (g, s, t) with s·self + t·other = g, the monic gcd.
+    pub fn xgcd(&self, other: &Self) -> (Self, Self, Self) {
+        let (gcd, s, t) = xgcd(&self.coefficients, &other.coefficients);
+        (from_trimmed(gcd), from_trimmed(s), from_trimmed(t))
+    }
+
+    /// Returns the inverse of `self` modulo `modulus`, or `None` if they are not coprime.
+    pub fn inverse_mod(&self, modulus: &Self) -> Option<Self> {
+        inverse_mod(&self.coefficients, &modulus.coefficients).map(from_trimmed)
+    }
+
+    /// True when the two polynomials share no common root in any extension of F.
+    pub fn is_coprime(&self, other: &Self) -> bool {
+        gcd(&self.coefficients, &other.coefficients) == [F::one()]
+    }
+}
@@ -1,15 +1,31 @@
+use crate::division::divide_by_linear;
+use crate::multipoint::{fast_interpolate, FAST_MULTIPOINT_THRESHOLD};
 use crate::operations::{add_polynomials, multiply_polynomials, scalar_mul};
 use crate::polynomial::DenseUnivariatePoly;
+use crate::univariate::UnivariatePolynomial;
 use ark_ff::PrimeField;
 
+/// Returns the polynomial of degree < n through (x_values[i], y_values[i]), with exactly n
+/// coefficients. Small inputs build M(x) = Π (x − x_i) once and divide out each factor in
+/// O(n), for O(n²) overall; from `FAST_MULTIPOINT_THRESHOLD` points on, a subproduct tree
+/// over FFT multiplication takes over.
 pub fn lagrange_interpolate<F: PrimeField>(
     x_values: &[F],
     y_values: &[F],
 ) -> DenseUnivariatePoly<F> {
+    if x_values.len() >= FAST_MULTIPOINT_THRESHOLD {
+        let mut poly = fast_interpolate(x_values, y_values);
+        poly.coefficients.resize(x_values.len(), F::zero());
+        return poly;
+    }
+
+    let vanishing = x_values.iter().fold(vec![F::one()], |acc, x| {
+        multiply_polynomials(acc, vec![-*x, F::one()])
+    });
     let mut final_poly = vec![F::zero()];
 
-    for (i, &x_val) in x_values.iter().enumerate() {
-        let current_poly = lagrange_basis(y_values[i], x_val, x_values);
+    for (&x_val, &y_val) in x_values.iter().zip(y_values.iter()) {
+        let current_poly = lagrange_basis(y_val, x_val, &vanishing);
         final_poly = add_polynomials(final_poly, current_poly);
     }
 
@@ -18,14 +34,9 @@ pub fn lagrange_interpolate<F: PrimeField>(
     }
 }
 
-fn lagrange_basis<F: PrimeField>(y: F, x0: F, x_values: &[F]) -> Vec<F> {
-    let mut numerator = vec![F::one()];
-
-    for &x in x_values.iter() {
-        if x != x0 {
-            numerator = multiply_polynomials(numerator, vec![-x, F::one()]);
-        }
-    }
+/// y · L_0(x), where L_0(x) = M(x) / (x − x0) scaled so that L_0(x0) = 1.
+fn lagrange_basis<F: PrimeField>(y: F, x0: F, vanishing: &[F]) -> Vec<F> {
+    let (numerator, _) = divide_by_linear(vanishing, x0);
 
     let denominator = DenseUnivariatePoly::new(numerator.clone()).evaluate(x0);
     scalar_mul(y / denominator, numerator)
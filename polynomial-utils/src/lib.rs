@@ -1,3 +1,9 @@
+pub mod barycentric;
+pub mod division;
+pub mod domain;
+pub mod gcd;
 pub mod interpolation;
+pub mod multipoint;
 pub mod operations;
 pub mod polynomial;
+pub mod univariate;
@@ -0,0 +1,151 @@
+use crate::division::div_rem;
+use crate::operations::{add_polynomials, multiply_polynomials};
+use crate::polynomial::DenseUnivariatePoly;
+use crate::univariate::{from_trimmed, trim_coefficients, UnivariatePolynomial};
+use ark_ff::{batch_inversion, PrimeField};
+
+/// Below this many points or coefficients, n Horner evaluations beat building the tree.
+pub const FAST_MULTIPOINT_THRESHOLD: usize = 64;
+
+/// The products M_{i,j} = Π (x − x_k) over dyadic blocks of the points, stored bottom-up:
+/// layer 0 holds the linear factors x − x_k and the last layer holds the single product
+/// M(x) = Π_k (x − x_k). An odd node at the end of a layer is carried up unchanged.
+///
+/// With FFT multiplication and Newton division underneath, evaluating at all n points or
+/// interpolating through them costs O(n log² n) instead of O(n²).
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubproductTree<F: PrimeField> {
+    pub points: Vec<F>,
+    layers: Vec<Vec<Vec<F>>>,
+}
+
+impl<F: PrimeField> SubproductTree<F> {
+    /// Panics if `points` is empty.
+    pub fn new(points: &[F]) -> Self {
+        if points.is_empty() {
+            panic!("Subproduct tree needs at least one point");
+        }
+
+        let mut layers = vec![points
+            .iter()
+            .map(|point| vec![-*point, F::one()])
+            .collect::<Vec<_>>()];
+
+        while layers.last().expect("tree has a layer").len() > 1 {
+            let next = layers
+                .last()
+                .expect("tree has a layer")
+                .chunks(2)
+                .map(|pair| match pair {
+                    [left, right] => multiply_polynomials(left.clone(), right.clone()),
+                    [single] => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+            layers.push(next);
+        }
+
+        Self {
+            points: points.to_vec(),
+            layers,
+        }
+    }
+
+    /// The vanishing polynomial M(x) = Π_k (x − x_k) of the points.
+    pub fn root(&self) -> &[F] {
+        &self.layers.last().expect("tree has a layer")[0]
+    }
+
+    /// Evaluates the polynomial with `coefficients` at every point by reducing it modulo each
+    /// node on the way down; the remainder at leaf x − x_k is the value at x_k.
+    pub fn evaluate(&self, coefficients: &[F]) -> Vec<F> {
+        let mut remainders = vec![div_rem(coefficients, self.root()).1];
+
+        for layer in self.layers.iter().rev().skip(1) {
+            remainders = layer
+                .iter()
+                .enumerate()
+                .map(|(i, node)| div_rem(&remainders[i / 2], node).1)
+                .collect();
+        }
+
+        remainders
+            .into_iter()
+            .map(|remainder| remainder.first().copied().unwrap_or(F::zero()))
+            .collect()
+    }
+
+    /// Returns the coefficients of the unique polynomial of degree < n taking `values` on the
+    /// points. With c_k = y_k / M'(x_k), it combines Σ c_k · M(x) / (x − x_k) up the tree as
+    /// left · M_right + right · M_left. Panics if the points are not distinct.
+    pub fn interpolate(&self, values: &[F]) -> Vec<F> {
+        if values.len() != self.points.len() {
+            panic!("Invalid number of values");
+        }
+
+        let mut derivative_values = self.evaluate(&derivative(self.root()));
+        if derivative_values.iter().any(|value| value.is_zero()) {
+            panic!("Interpolation points must be distinct");
+        }
+        batch_inversion(&mut derivative_values);
+
+        let mut current: Vec<Vec<F>> = values
+            .iter()
+            .zip(derivative_values.iter())
+            .map(|(value, inverse)| vec![*value * inverse])
+            .collect();
+
+        for layer in self.layers.iter().take(self.layers.len() - 1) {
+            current = current
+                .chunks(2)
+                .zip(layer.chunks(2))
+                .map(|(sums, nodes)| match (sums, nodes) {
+                    ([left, right], [left_node, right_node]) => add_polynomials(
+                        multiply_polynomials(left.clone(), right_node.clone()),
+                        multiply_polynomials(right.clone(), left_node.clone()),
+                    ),
+                    ([single], _) => single.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        trim_coefficients(current.remove(0))
+    }
+}
+
+/// Evaluates the polynomial with `coefficients` at each of `points`. Small inputs use Horner
+/// per point; larger ones go through a subproduct tree.
+pub fn evaluate_many<F: PrimeField>(coefficients: &[F], points: &[F]) -> Vec<F> {
+    if points.len().min(coefficients.len()) < FAST_MULTIPOINT_THRESHOLD {
+        let poly = DenseUnivariatePoly::new(coefficients.to_vec());
+        return points.iter().map(|point| poly.evaluate(*point)).collect();
+    }
+
+    SubproductTree::new(points).evaluate(coefficients)
+}
+
+/// Interpolates through (x_values[i], y_values[i]) via a subproduct tree.
+pub fn fast_interpolate<F: PrimeField>(x_values: &[F], y_values: &[F]) -> DenseUnivariatePoly<F> {
+    if x_values.is_empty() {
+        return DenseUnivariatePoly::new(vec![F::zero()]);
+    }
+
+    from_trimmed(SubproductTree::new(x_values).interpolate(y_values))
+}
+
+impl<F: PrimeField> DenseUnivariatePoly<F> {
+    /// Evaluates at every point in `points`; see `evaluate_many`.
+    pub fn evaluate_many(&self, points: &[F]) -> Vec<F> {
+        evaluate_many(&self.coefficients, points)
+    }
+}
+
+fn derivative<F: PrimeField>(coefficients: &[F]) -> Vec<F> {
+    coefficients
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, coeff)| F::from(i as u64) * coeff)
+        .collect()
+}
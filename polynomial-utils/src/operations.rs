@@ -1,10 +1,24 @@
+use crate::domain::{fft_multiply, Radix2Domain};
 use ark_ff::PrimeField;
 
+/// Below this many coefficients in the smaller operand, schoolbook multiplication beats
+/// the NTT.
+pub const FFT_MULTIPLICATION_THRESHOLD: usize = 64;
+
 pub fn scalar_mul<F: PrimeField>(scalar: F, poly: Vec<F>) -> Vec<F> {
     poly.iter().map(|coeff| *coeff * scalar).collect()
 }
 
+/// Multiplies in coefficient form, switching to NTT multiplication once both operands have
+/// at least `FFT_MULTIPLICATION_THRESHOLD` coefficients. Fields without a large enough
+/// power-of-two subgroup, such as the BN254 base field, always use schoolbook.
 pub fn multiply_polynomials<F: PrimeField>(left: Vec<F>, right: Vec<F>) -> Vec<F> {
+    if left.len().min(right.len()) >= FFT_MULTIPLICATION_THRESHOLD
+        && Radix2Domain::<F>::new(left.len() + right.len() - 1).is_some()
+    {
+        return fft_multiply(&left, &right);
+    }
+
     let mut product = vec![F::zero(); left.len() + right.len() - 1];
 
     for (i, &left_coeff) in left.iter().enumerate() {
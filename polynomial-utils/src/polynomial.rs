@@ -1,6 +1,8 @@
 use ark_ff::PrimeField;
 
-#[derive(Debug, Clone)]
+/// Coefficients in ascending degree order. Degree, evaluation and division come from
+/// `UnivariatePolynomial`, which must be in scope to call them.
+#[derive(Debug, Clone, PartialEq)]
 pub struct DenseUnivariatePoly<F: PrimeField> {
     pub coefficients: Vec<F>,
 }
@@ -11,16 +13,4 @@ impl<F: PrimeField> DenseUnivariatePoly<F> {
             coefficients: coeffs,
         }
     }
-
-    pub fn degree(&self) -> u32 {
-        self.coefficients.len() as u32 - 1
-    }
-
-    pub fn evaluate(&self, value: F) -> F {
-        let mut result = F::zero();
-        for (index, coeff) in self.coefficients.iter().enumerate() {
-            result += *coeff * value.pow([index as u64]);
-        }
-        result
-    }
 }
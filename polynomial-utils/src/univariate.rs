@@ -0,0 +1,119 @@
+use crate::polynomial::DenseUnivariatePoly;
+use ark_ff::PrimeField;
+
+/// The operations shared by every univariate representation, so downstream code can be
+/// generic over dense or sparse storage. Coefficients are in ascending degree order.
+pub trait UnivariatePolynomial<F: PrimeField>: Sized {
+    fn from_coefficients(coefficients: Vec<F>) -> Self;
+
+    /// Returns the coefficients without trailing zeros; empty for the zero polynomial.
+    fn coefficients(&self) -> Vec<F>;
+
+    /// Returns `None` for the zero polynomial.
+    fn degree(&self) -> Option<usize> {
+        self.coefficients().len().checked_sub(1)
+    }
+
+    fn evaluate(&self, value: F) -> F;
+
+    fn add(&self, other: &Self) -> Self;
+
+    fn mul(&self, other: &Self) -> Self;
+
+    fn scalar_mul(&self, scalar: F) -> Self;
+
+    /// Returns (quotient, remainder) with deg(remainder) < deg(divisor). Panics on a zero
+    /// divisor.
+    fn div_rem(&self, divisor: &Self) -> (Self, Self);
+
+    /// Returns the lowest-degree polynomial through (x_values[i], y_values[i]).
+    fn interpolate(x_values: &[F], y_values: &[F]) -> Self;
+}
+
+/// Drops trailing zero coefficients.
+pub fn trim_coefficients<F: PrimeField>(mut coefficients: Vec<F>) -> Vec<F> {
+    while coefficients.last().is_some_and(|coeff| coeff.is_zero()) {
+        coefficients.pop();
+    }
+
+    coefficients
+}
+
+/// Builds a polynomial from trimmed coefficients. Dense representations store the zero
+/// polynomial as [0] rather than with no coefficients, so an empty input becomes [0].
+pub fn from_trimmed<F: PrimeField, P: UnivariatePolynomial<F>>(coefficients: Vec<F>) -> P {
+    if coefficients.is_empty() {
+        P::from_coefficients(vec![F::zero()])
+    } else {
+        P::from_coefficients(coefficients)
+    }
+}
+
+/// Implements `UnivariatePolynomial` for a dense type with a `coefficients: Vec<F>` field in
+/// ascending degree order and a `new(Vec<F>)` constructor, so every dense representation
+/// shares one implementation. The caller must depend on `ark-ff`.
+#[macro_export]
+macro_rules! impl_dense_univariate_polynomial {
+    ($poly:ident) => {
+        impl<F: ::ark_ff::PrimeField> $crate::univariate::UnivariatePolynomial<F> for $poly<F> {
+            fn from_coefficients(coefficients: Vec<F>) -> Self {
+                $poly::new(coefficients)
+            }
+
+            fn coefficients(&self) -> Vec<F> {
+                $crate::univariate::trim_coefficients(self.coefficients.clone())
+            }
+
+            // Horner's rule: one multiplication and one addition per coefficient.
+            fn evaluate(&self, value: F) -> F {
+                self.coefficients
+                    .iter()
+                    .rev()
+                    .fold(F::zero(), |acc, coeff| acc * value + coeff)
+            }
+
+            fn add(&self, other: &Self) -> Self {
+                $poly::new($crate::operations::add_polynomials(
+                    self.coefficients.clone(),
+                    other.coefficients.clone(),
+                ))
+            }
+
+            fn mul(&self, other: &Self) -> Self {
+                if self.coefficients.is_empty() || other.coefficients.is_empty() {
+                    return $poly::new(vec![F::zero()]);
+                }
+
+                $poly::new($crate::operations::multiply_polynomials(
+                    self.coefficients.clone(),
+                    other.coefficients.clone(),
+                ))
+            }
+
+            fn scalar_mul(&self, scalar: F) -> Self {
+                $poly::new($crate::operations::scalar_mul(
+                    scalar,
+                    self.coefficients.clone(),
+                ))
+            }
+
+            fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+                let (quotient, remainder) =
+                    $crate::division::div_rem(&self.coefficients, &divisor.coefficients);
+
+                (
+                    $crate::univariate::from_trimmed(quotient),
+                    $crate::univariate::from_trimmed(remainder),
+                )
+            }
+
+            fn interpolate(x_values: &[F], y_values: &[F]) -> Self {
+                $poly::new(
+                    $crate::interpolation::lagrange_interpolate(x_values, y_values).coefficients,
+                )
+            }
+        }
+    };
+}
+
+impl_dense_univariate_polynomial!(DenseUnivariatePoly);
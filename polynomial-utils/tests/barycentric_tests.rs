@@ -0,0 +1,66 @@
+use ark_bn254::Fq;
+use polynomial_utils::barycentric::{evaluate_from_evaluations, BarycentricWeights};
+use polynomial_utils::interpolation::lagrange_interpolate;
+use polynomial_utils::polynomial::DenseUnivariatePoly;
+use polynomial_utils::univariate::UnivariatePolynomial;
+
+#[test]
+fn test_barycentric_matches_lagrange() {
+    let x_vals = vec![Fq::from(1), Fq::from(4), Fq::from(9), Fq::from(13)];
+    let y_vals = vec![Fq::from(6), Fq::from(11), Fq::from(18), Fq::from(2)];
+    let weights = BarycentricWeights::new(&x_vals);
+    let poly = lagrange_interpolate(&x_vals, &y_vals);
+
+    for at in [0, 2, 7, 100] {
+        assert_eq!(
+            weights.evaluate(&y_vals, Fq::from(at)),
+            poly.evaluate(Fq::from(at))
+        );
+    }
+
+    // Evaluating at an interpolation point returns its value directly
+    assert_eq!(weights.evaluate(&y_vals, Fq::from(9)), Fq::from(18));
+}
+
+#[test]
+fn test_consecutive_points() {
+    let poly = DenseUnivariatePoly::new(vec![Fq::from(3), Fq::from(0), Fq::from(5), Fq::from(1)]);
+    let values: Vec<Fq> = (0..4u64).map(|x| poly.evaluate(Fq::from(x))).collect();
+    let weights = BarycentricWeights::<Fq>::for_consecutive_points(4);
+
+    assert_eq!(
+        weights,
+        BarycentricWeights::new(&[Fq::from(0), Fq::from(1), Fq::from(2), Fq::from(3)])
+    );
+    assert_eq!(
+        weights.evaluate(&values, Fq::from(11)),
+        poly.evaluate(Fq::from(11))
+    );
+}
+
+#[test]
+fn test_evaluate_from_evaluations() {
+    // f(x) = x² + 2x + 3 through (1, 6), (2, 11), (3, 18); f(0) = 3
+    let x_vals = vec![Fq::from(1), Fq::from(2), Fq::from(3)];
+    let y_vals = vec![Fq::from(6), Fq::from(11), Fq::from(18)];
+
+    assert_eq!(
+        evaluate_from_evaluations(&x_vals, &y_vals, Fq::from(0)),
+        Fq::from(3)
+    );
+
+    // Repeated evaluations share one set of weights and agree with the one-off helper
+    let weights = BarycentricWeights::new(&x_vals);
+    for at in [0, 5, 8, 40] {
+        assert_eq!(
+            weights.evaluate(&y_vals, Fq::from(at)),
+            evaluate_from_evaluations(&x_vals, &y_vals, Fq::from(at))
+        );
+    }
+}
+
+#[test]
+#[should_panic(expected = "Interpolation points must be distinct")]
+fn test_repeated_points() {
+    BarycentricWeights::new(&[Fq::from(1), Fq::from(1)]);
+}
@@ -0,0 +1,100 @@
+use ark_bn254::Fq;
+use ark_ff::{AdditiveGroup, Field, PrimeField};
+use polynomial_utils::division::{div_rem, divide_by_linear, divide_by_vanishing};
+use polynomial_utils::operations::{add_polynomials, multiply_polynomials};
+use polynomial_utils::polynomial::DenseUnivariatePoly;
+use polynomial_utils::univariate::UnivariatePolynomial;
+
+fn coefficients(values: &[u64]) -> Vec<Fq> {
+    values.iter().map(|value| Fq::from(*value)).collect()
+}
+
+// Checks dividend = quotient·divisor + remainder with deg(remainder) < deg(divisor).
+fn assert_division<F: PrimeField>(dividend: &[F], divisor: &[F]) {
+    let (quotient, remainder) = div_rem(dividend, divisor);
+    let mut reconstructed = add_polynomials(
+        multiply_polynomials(quotient.clone(), divisor.to_vec()),
+        remainder.clone(),
+    );
+    reconstructed.resize(dividend.len().max(reconstructed.len()), F::zero());
+    let mut expected = dividend.to_vec();
+    expected.resize(reconstructed.len(), F::zero());
+
+    assert_eq!(reconstructed, expected);
+    assert!(remainder.len() < divisor.len());
+}
+
+#[test]
+fn test_long_division() {
+    // (2x³ + 3x² + 5x + 7) / (3x² + 1)
+    let dividend = coefficients(&[7, 5, 3, 2]);
+    let divisor = coefficients(&[1, 0, 3]);
+    assert_division(&dividend, &divisor);
+
+    // (x² + 3x + 2) / (x + 1) = x + 2
+    assert_eq!(
+        div_rem(&coefficients(&[2, 3, 1]), &coefficients(&[1, 1])),
+        (coefficients(&[2, 1]), vec![])
+    );
+
+    // A dividend of lower degree is its own remainder
+    assert_eq!(
+        div_rem(&coefficients(&[4, 1]), &coefficients(&[1, 2, 3])),
+        (vec![], coefficients(&[4, 1]))
+    );
+}
+
+#[test]
+fn test_synthetic_division() {
+    let dividend = coefficients(&[7, 5, 3, 2]);
+    let poly = DenseUnivariatePoly::new(dividend.clone());
+    let a = Fq::from(4);
+
+    let (quotient, remainder) = divide_by_linear(&dividend, a);
+    assert_eq!(remainder, poly.evaluate(a));
+    assert_eq!(quotient.len(), 3);
+    assert_division(&dividend, &[-a, Fq::ONE]);
+}
+
+#[test]
+fn test_division_by_vanishing_polynomial() {
+    let dividend: Vec<Fq> = (0..11u64).map(|i| Fq::from(i * i + 1)).collect();
+    let c = Fq::from(3);
+
+    let (quotient, remainder) = divide_by_vanishing(&dividend, 4, c);
+    assert_eq!(
+        (quotient, remainder),
+        div_rem(&dividend, &[-c, Fq::ZERO, Fq::ZERO, Fq::ZERO, Fq::ONE])
+    );
+    assert_division(&dividend, &[-c, Fq::ZERO, Fq::ZERO, Fq::ZERO, Fq::ONE]);
+
+    // x⁴ − 1 divides x⁸ − 1 exactly
+    let mut dividend = vec![Fq::ZERO; 9];
+    dividend[0] = -Fq::ONE;
+    dividend[8] = Fq::ONE;
+    let (quotient, remainder) = divide_by_vanishing(&dividend, 4, Fq::ONE);
+    assert_eq!(
+        quotient,
+        vec![Fq::ONE, Fq::ZERO, Fq::ZERO, Fq::ZERO, Fq::ONE]
+    );
+    assert!(remainder.is_empty());
+}
+
+#[test]
+fn test_dense_div_rem() {
+    let poly = DenseUnivariatePoly::new(coefficients(&[2, 3, 1]));
+    let (quotient, remainder) = poly.div_rem(&DenseUnivariatePoly::new(coefficients(&[1, 1])));
+
+    assert_eq!(quotient.coefficients, coefficients(&[2, 1]));
+    assert_eq!(remainder.coefficients, coefficients(&[0]));
+
+    let (quotient, value) = poly.div_by_linear(Fq::from(5));
+    assert_eq!(value, Fq::from(42));
+    assert_eq!(quotient.coefficients, coefficients(&[8, 1]));
+}
+
+#[test]
+#[should_panic(expected = "Division by zero polynomial")]
+fn test_division_by_zero() {
+    div_rem(&coefficients(&[1, 2]), &coefficients(&[0, 0]));
+}
@@ -0,0 +1,101 @@
+use ark_bn254::{Fq, Fr};
+use ark_ff::{Field, PrimeField};
+use polynomial_utils::domain::{fft_multiply, Radix2Domain};
+use polynomial_utils::operations::{multiply_polynomials, FFT_MULTIPLICATION_THRESHOLD};
+use polynomial_utils::polynomial::DenseUnivariatePoly;
+use polynomial_utils::univariate::UnivariatePolynomial;
+
+fn sample_coefficients<F: PrimeField>(len: u64, seed: u64) -> Vec<F> {
+    (0..len)
+        .map(|i| F::from(i * i * seed + 3 * i + seed))
+        .collect()
+}
+
+fn schoolbook<F: PrimeField>(left: &[F], right: &[F]) -> Vec<F> {
+    let mut product = vec![F::zero(); left.len() + right.len() - 1];
+    for (i, a) in left.iter().enumerate() {
+        for (j, b) in right.iter().enumerate() {
+            product[i + j] += *a * b;
+        }
+    }
+    product
+}
+
+#[test]
+fn test_domain_elements() {
+    let domain = Radix2Domain::<Fr>::new(6).unwrap();
+
+    assert_eq!(domain.size, 8);
+    assert_eq!(domain.log_size, 3);
+    assert_eq!(domain.generator.pow([8]), Fr::from(1));
+    assert_ne!(domain.generator.pow([4]), Fr::from(1));
+    assert_eq!(domain.elements().len(), 8);
+}
+
+#[test]
+fn test_fft_evaluates_over_domain() {
+    let domain = Radix2Domain::<Fr>::new(8).unwrap();
+    let coefficients = sample_coefficients(6, 5);
+    let poly = DenseUnivariatePoly::new(coefficients.clone());
+
+    let expected: Vec<Fr> = domain
+        .elements()
+        .into_iter()
+        .map(|x| poly.evaluate(x))
+        .collect();
+
+    assert_eq!(domain.fft(&coefficients), expected);
+    assert_eq!(domain.ifft(&expected)[..6], coefficients[..]);
+    assert_eq!(
+        domain.interpolate(&expected).evaluate(Fr::from(9)),
+        poly.evaluate(Fr::from(9))
+    );
+}
+
+#[test]
+fn test_coset_fft() {
+    let domain = Radix2Domain::<Fr>::new(16).unwrap();
+    let coefficients = sample_coefficients(16, 2);
+    let poly = DenseUnivariatePoly::new(coefficients.clone());
+    let offset = Fr::from(7);
+
+    let evaluations = domain.coset_fft(&coefficients, offset);
+    for (x, evaluation) in domain.elements().into_iter().zip(evaluations.iter()) {
+        assert_eq!(poly.evaluate(offset * x), *evaluation);
+    }
+
+    assert_eq!(domain.coset_ifft(&evaluations, offset), coefficients);
+}
+
+#[test]
+fn test_fft_multiply_matches_schoolbook() {
+    let left: Vec<Fr> = sample_coefficients(100, 3);
+    let right: Vec<Fr> = sample_coefficients(77, 11);
+
+    assert_eq!(fft_multiply(&left, &right), schoolbook(&left, &right));
+    assert_eq!(
+        fft_multiply(&[Fr::from(2)], &[Fr::from(3)]),
+        vec![Fr::from(6)]
+    );
+}
+
+#[test]
+fn test_multiply_polynomials_above_threshold() {
+    let n = FFT_MULTIPLICATION_THRESHOLD as u64 + 5;
+
+    let left: Vec<Fr> = sample_coefficients(n, 4);
+    let right: Vec<Fr> = sample_coefficients(n, 9);
+    assert_eq!(
+        multiply_polynomials(left.clone(), right.clone()),
+        schoolbook(&left, &right)
+    );
+
+    // The BN254 base field has two-adicity 1, so it falls back to schoolbook.
+    assert!(Radix2Domain::<Fq>::new(4).is_none());
+    let left: Vec<Fq> = sample_coefficients(n, 4);
+    let right: Vec<Fq> = sample_coefficients(n, 9);
+    assert_eq!(
+        multiply_polynomials(left.clone(), right.clone()),
+        schoolbook(&left, &right)
+    );
+}
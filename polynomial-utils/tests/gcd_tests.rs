@@ -0,0 +1,130 @@
+use ark_bn254::Fq;
+use ark_ff::PrimeField;
+use polynomial_utils::division::div_rem;
+use polynomial_utils::gcd::{gcd, inverse_mod, xgcd, xgcd_with_bound};
+use polynomial_utils::operations::{add_polynomials, multiply_polynomials};
+use polynomial_utils::polynomial::DenseUnivariatePoly;
+use polynomial_utils::univariate::trim_coefficients;
+
+fn coefficients(values: &[u64]) -> Vec<Fq> {
+    values.iter().map(|value| Fq::from(*value)).collect()
+}
+
+// Π (x − root) for the given roots.
+fn from_roots(roots: &[u64]) -> Vec<Fq> {
+    roots.iter().fold(vec![Fq::from(1)], |acc, root| {
+        multiply_polynomials(acc, vec![-Fq::from(*root), Fq::from(1)])
+    })
+}
+
+fn linear_combination<F: PrimeField>(s: &[F], a: &[F], t: &[F], b: &[F]) -> Vec<F> {
+    let product = |x: &[F], y: &[F]| {
+        if x.is_empty() || y.is_empty() {
+            vec![]
+        } else {
+            multiply_polynomials(x.to_vec(), y.to_vec())
+        }
+    };
+
+    trim_coefficients(add_polynomials(product(s, a), product(t, b)))
+}
+
+#[test]
+fn test_gcd_of_shared_roots() {
+    let left = from_roots(&[1, 2, 3, 7]);
+    let right = from_roots(&[2, 5, 7]);
+
+    assert_eq!(gcd(&left, &right), from_roots(&[2, 7]));
+    assert_eq!(gcd(&right, &left), from_roots(&[2, 7]));
+}
+
+#[test]
+fn test_gcd_is_monic_and_handles_zero() {
+    let poly = coefficients(&[4, 6, 2]);
+
+    // gcd(p, 0) is p made monic
+    assert_eq!(gcd(&poly, &[]), coefficients(&[2, 3, 1]));
+    assert_eq!(gcd::<Fq>(&[], &[]), vec![]);
+}
+
+#[test]
+fn test_xgcd_bezout_identity() {
+    let left = from_roots(&[1, 4, 9, 10, 11]);
+    let right = multiply_polynomials(from_roots(&[4, 6]), coefficients(&[3, 1, 5]));
+    let (g, s, t) = xgcd(&left, &right);
+
+    assert_eq!(g, from_roots(&[4]));
+    assert_eq!(linear_combination(&s, &left, &t, &right), g);
+}
+
+#[test]
+fn test_inverse_mod() {
+    let modulus = coefficients(&[1, 0, 0, 0, 1, 3]);
+    let poly = coefficients(&[5, 2, 8]);
+    let inverse = inverse_mod(&poly, &modulus).unwrap();
+
+    assert!(inverse.len() < modulus.len());
+    let product = multiply_polynomials(poly, inverse);
+    assert_eq!(div_rem(&product, &modulus).1, coefficients(&[1]));
+}
+
+#[test]
+fn test_inverse_mod_fails_on_common_factor() {
+    let modulus = from_roots(&[2, 3, 5]);
+    let poly = from_roots(&[3, 8]);
+
+    assert_eq!(inverse_mod(&poly, &modulus), None);
+}
+
+#[test]
+fn test_coprime() {
+    let left = DenseUnivariatePoly::new(from_roots(&[1, 2]));
+    let right = DenseUnivariatePoly::new(from_roots(&[3, 4]));
+    let shared = DenseUnivariatePoly::new(from_roots(&[2, 6]));
+
+    assert!(left.is_coprime(&right));
+    assert!(!left.is_coprime(&shared));
+    assert_eq!(
+        left.gcd(&shared),
+        DenseUnivariatePoly::new(from_roots(&[2]))
+    );
+}
+
+#[test]
+fn test_rational_reconstruction() {
+    // Recover n/d from u ≡ n · d⁻¹ mod M, with deg n < 2 and deg d ≤ 2
+    let modulus = from_roots(&[1, 2, 3, 4, 5, 6]);
+    let numerator = coefficients(&[7, 3]);
+    let denominator = coefficients(&[2, 0, 1]);
+    let u = div_rem(
+        &multiply_polynomials(
+            numerator.clone(),
+            inverse_mod(&denominator, &modulus).unwrap(),
+        ),
+        &modulus,
+    )
+    .1;
+
+    let (r, _, t) = xgcd_with_bound(&modulus, &u, 2);
+
+    // r / t equals n / d up to a common scalar
+    assert_eq!(
+        multiply_polynomials(r, denominator),
+        multiply_polynomials(t, numerator)
+    );
+}
+
+#[test]
+fn test_xgcd_with_bound_stops_at_exactly_bound_coefficients() {
+    let left = from_roots(&[1, 2, 3, 4, 5, 6]);
+    let right = coefficients(&[3, 1, 4, 1, 5, 9]);
+
+    // The remainders shrink one coefficient per step, so one reaches exactly `bound`
+    let (r, s, t) = xgcd_with_bound(&left, &right, 4);
+    assert_eq!(r.len(), 4);
+    assert_eq!(linear_combination(&s, &left, &t, &right), r);
+
+    // A `right` already within the bound is returned untouched
+    let (r, s, t) = xgcd_with_bound(&left, &right, right.len());
+    assert_eq!((r, s, t), (right, vec![], coefficients(&[1])));
+}
@@ -1,5 +1,6 @@
-use ark_bn254::Fq;
+use ark_bn254::{Fq, Fr};
 use polynomial_utils::interpolation::lagrange_interpolate;
+use polynomial_utils::polynomial::DenseUnivariatePoly;
 
 
 #[test]
@@ -14,3 +15,26 @@ fn test_lagrange_interpolation() {
         vec![Fq::from(3), Fq::from(2), Fq::from(1)]
     );
 }
+
+#[test]
+fn test_lagrange_interpolation_above_threshold() {
+    // Enough points to go through the subproduct tree
+    let poly = DenseUnivariatePoly::new((0..300u64).map(|i| Fr::from(i * i + 7)).collect());
+    let x_vals: Vec<Fr> = (0..300u64).map(|i| Fr::from(2 * i + 5)).collect();
+    let y_vals = poly.evaluate_many(&x_vals);
+
+    assert_eq!(lagrange_interpolate(&x_vals, &y_vals), poly);
+}
+
+#[test]
+fn test_lagrange_interpolation_keeps_one_coefficient_per_point() {
+    // A line through 80 points still comes back with 80 coefficients
+    let x_vals: Vec<Fq> = (0..80u64).map(Fq::from).collect();
+    let y_vals: Vec<Fq> = (0..80u64).map(|x| Fq::from(4 * x + 1)).collect();
+
+    let poly = lagrange_interpolate(&x_vals, &y_vals);
+
+    assert_eq!(poly.coefficients.len(), 80);
+    assert_eq!(poly.coefficients[..2], [Fq::from(1), Fq::from(4)]);
+    assert!(poly.coefficients[2..].iter().all(|c| *c == Fq::from(0)));
+}
@@ -0,0 +1,93 @@
+use ark_bn254::{Fq, Fr};
+use ark_ff::PrimeField;
+use polynomial_utils::division::div_rem;
+use polynomial_utils::interpolation::lagrange_interpolate;
+use polynomial_utils::multipoint::{evaluate_many, fast_interpolate, SubproductTree};
+use polynomial_utils::operations::{add_polynomials, multiply_polynomials};
+use polynomial_utils::polynomial::DenseUnivariatePoly;
+use polynomial_utils::univariate::UnivariatePolynomial;
+
+fn coefficients<F: PrimeField>(count: u64, seed: u64) -> Vec<F> {
+    (0..count)
+        .map(|i| F::from((i * i * seed + 3 * i + 1) % 1009))
+        .collect()
+}
+
+#[test]
+fn test_horner_evaluation() {
+    // 2 + 3x + x³ at x = 2
+    let poly = DenseUnivariatePoly::new(vec![Fq::from(2), Fq::from(3), Fq::from(0), Fq::from(1)]);
+    assert_eq!(poly.evaluate(Fq::from(2)), Fq::from(16));
+}
+
+#[test]
+fn test_subproduct_tree_evaluation() {
+    let points: Vec<Fq> = [3, 8, 11, 20, 27].into_iter().map(Fq::from).collect();
+    let poly = DenseUnivariatePoly::new(coefficients::<Fq>(9, 7));
+    let tree = SubproductTree::new(&points);
+
+    let expected: Vec<Fq> = points.iter().map(|x| poly.evaluate(*x)).collect();
+    assert_eq!(tree.evaluate(&poly.coefficients), expected);
+
+    // The root vanishes on every point
+    let root = DenseUnivariatePoly::new(tree.root().to_vec());
+    assert!(points.iter().all(|x| root.evaluate(*x) == Fq::from(0)));
+}
+
+#[test]
+fn test_subproduct_tree_interpolation_matches_lagrange() {
+    let x_values: Vec<Fq> = [1, 4, 9, 13, 22, 30, 41]
+        .into_iter()
+        .map(Fq::from)
+        .collect();
+    let y_values: Vec<Fq> = [6, 11, 18, 2, 0, 5, 7].into_iter().map(Fq::from).collect();
+
+    assert_eq!(
+        fast_interpolate(&x_values, &y_values),
+        lagrange_interpolate(&x_values, &y_values)
+    );
+}
+
+#[test]
+fn test_evaluate_many_above_threshold() {
+    // Large enough to take the tree, FFT multiplication and Newton division paths
+    let poly = DenseUnivariatePoly::new(coefficients::<Fr>(300, 13));
+    let points: Vec<Fr> = (0..200u64).map(|i| Fr::from(5 * i + 2)).collect();
+
+    let expected: Vec<Fr> = points.iter().map(|x| poly.evaluate(*x)).collect();
+    assert_eq!(poly.evaluate_many(&points), expected);
+    assert_eq!(
+        evaluate_many(&poly.coefficients, &points[..3]),
+        expected[..3]
+    );
+}
+
+#[test]
+fn test_fast_interpolation_round_trip() {
+    let poly = DenseUnivariatePoly::new(coefficients::<Fr>(150, 11));
+    let points: Vec<Fr> = (0..150u64).map(|i| Fr::from(3 * i + 1)).collect();
+    let values = poly.evaluate_many(&points);
+
+    assert_eq!(fast_interpolate(&points, &values), poly);
+}
+
+#[test]
+fn test_newton_division_matches_identity() {
+    let dividend: Vec<Fr> = coefficients(400, 17);
+    let divisor: Vec<Fr> = coefficients(130, 5);
+    let (quotient, remainder) = div_rem(&dividend, &divisor);
+
+    assert!(remainder.len() < divisor.len());
+    assert_eq!(
+        add_polynomials(multiply_polynomials(quotient, divisor), remainder),
+        dividend
+    );
+}
+
+#[test]
+#[should_panic(expected = "Interpolation points must be distinct")]
+fn test_interpolation_rejects_repeated_points() {
+    let x_values = vec![Fq::from(1), Fq::from(2), Fq::from(1)];
+    let y_values = vec![Fq::from(1), Fq::from(2), Fq::from(3)];
+    fast_interpolate(&x_values, &y_values);
+}
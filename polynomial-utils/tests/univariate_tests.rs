@@ -0,0 +1,53 @@
+use ark_bn254::Fq;
+use ark_ff::PrimeField;
+use polynomial_utils::polynomial::DenseUnivariatePoly;
+use polynomial_utils::univariate::UnivariatePolynomial;
+
+// Written against the trait only, as downstream generic code would be.
+fn square_plus_one<F: PrimeField, P: UnivariatePolynomial<F>>(poly: &P) -> P {
+    poly.mul(poly).add(&P::from_coefficients(vec![F::one()]))
+}
+
+#[test]
+fn test_trait_degree_ignores_trailing_zeros() {
+    let poly = DenseUnivariatePoly::new(vec![Fq::from(4), Fq::from(1), Fq::from(0)]);
+    let zero = DenseUnivariatePoly::new(vec![Fq::from(0)]);
+
+    assert_eq!(poly.degree(), Some(1));
+    assert_eq!(zero.degree(), None);
+    assert_eq!(poly.coefficients(), vec![Fq::from(4), Fq::from(1)]);
+}
+
+#[test]
+fn test_degree_of_empty_coefficients_is_none() {
+    assert_eq!(DenseUnivariatePoly::<Fq>::new(vec![]).degree(), None);
+}
+
+#[test]
+fn test_generic_arithmetic() {
+    // (x + 2)² + 1 = x² + 4x + 5
+    let poly = DenseUnivariatePoly::new(vec![Fq::from(2), Fq::from(1)]);
+    let result = square_plus_one(&poly);
+
+    assert_eq!(
+        result.coefficients(),
+        vec![Fq::from(5), Fq::from(4), Fq::from(1)]
+    );
+    assert_eq!(
+        result.scalar_mul(Fq::from(2)).evaluate(Fq::from(3)),
+        Fq::from(52)
+    );
+}
+
+#[test]
+fn test_trait_interpolation() {
+    let x_values = vec![Fq::from(1), Fq::from(2), Fq::from(3)];
+    let y_values = vec![Fq::from(6), Fq::from(11), Fq::from(18)];
+
+    let poly = DenseUnivariatePoly::interpolate(&x_values, &y_values);
+
+    assert_eq!(
+        poly.coefficients(),
+        vec![Fq::from(3), Fq::from(2), Fq::from(1)]
+    );
+}
@@ -0,0 +1,540 @@
+use crate::pcs::MultilinearPCS;
+use ark_bn254::{Fq, Fr, G1Affine, G1Projective};
+use ark_ec::{AffineRepr, CurveGroup, VariableBaseMSM};
+use ark_ff::{BigInteger, Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::Rng;
+use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
+use multilinear_polynomial::eq_polynomial::eq_evaluations;
+use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+use sha3::{Digest, Keccak256};
+
+/// Pedersen generators for polynomials in `num_of_vars` variables.
+#[derive(Clone, Debug)]
+pub struct HyraxParams {
+    pub num_of_vars: usize,
+    /// One generator per matrix column.
+    pub generators: Vec<G1Affine>,
+    /// Extra generator binding the inner product in the IPA.
+    pub inner_product_generator: G1Affine,
+}
+
+/// One Pedersen commitment per matrix row.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct HyraxCommitment {
+    pub row_commitments: Vec<G1Affine>,
+}
+
+/// The plain Hyrax opening: the row vector eq(r_rows, ·)ᵀM, of size √N.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct HyraxOpening {
+    pub evaluation_row: Vec<Fr>,
+}
+
+/// A Bulletproofs inner product argument replacing the √N row with 2·log(√N) points.
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct IpaProof {
+    pub left: Vec<G1Affine>,
+    pub right: Vec<G1Affine>,
+    pub final_scalar: Fr,
+}
+
+/// A transparent multilinear commitment over BN254 G1 (Wahby et al., "Hyrax").
+///
+/// The evaluations form a 2^⌊n/2⌋ × 2^⌈n/2⌉ matrix M with rows indexed by the leading
+/// variables. Each row gets a Pedersen vector commitment; an evaluation W(r) =
+/// eq(r_rows, ·)ᵀ · M · eq(r_cols, ·) is opened through the homomorphically combined row.
+pub struct Hyrax;
+
+impl Hyrax {
+    /// Derives generators by hashing to the curve, so nobody knows their discrete logs.
+    pub fn setup(num_of_vars: usize) -> HyraxParams {
+        let (_, num_col_vars) = matrix_shape(num_of_vars);
+
+        HyraxParams {
+            num_of_vars,
+            generators: (0..1u64 << num_col_vars)
+                .map(|index| hash_to_g1(b"hyrax-generator", index))
+                .collect(),
+            inner_product_generator: hash_to_g1(b"hyrax-inner-product", 0),
+        }
+    }
+
+    pub fn commit(params: &HyraxParams, polynomial: &MultilinearPoly<Fr>) -> HyraxCommitment {
+        if polynomial.num_of_vars != params.num_of_vars {
+            panic!("Mismatched number of variables");
+        }
+
+        let row_commitments: Vec<G1Projective> = polynomial
+            .evaluation
+            .chunks_exact(params.generators.len())
+            .map(|row| msm(&params.generators, row))
+            .collect();
+
+        HyraxCommitment {
+            row_commitments: G1Projective::normalize_batch(&row_commitments),
+        }
+    }
+
+    /// Opens at `point`, returning W(point) and the combined row.
+    pub fn open(
+        params: &HyraxParams,
+        polynomial: &MultilinearPoly<Fr>,
+        point: &[Fr],
+    ) -> (Fr, HyraxOpening) {
+        let (evaluation_row, col_weights) = evaluation_row(params, polynomial, point);
+
+        (
+            inner_product(&evaluation_row, &col_weights),
+            HyraxOpening { evaluation_row },
+        )
+    }
+
+    /// Checks that the combined row is consistent with the row commitments and evaluates to `value`.
+    pub fn verify(
+        params: &HyraxParams,
+        commitment: &HyraxCommitment,
+        point: &[Fr],
+        value: Fr,
+        opening: &HyraxOpening,
+    ) -> bool {
+        let Some((combined_commitment, col_weights)) =
+            combined_commitment(params, commitment, point)
+        else {
+            return false;
+        };
+
+        opening.evaluation_row.len() == params.generators.len()
+            && inner_product(&opening.evaluation_row, &col_weights) == value
+            && msm(&params.generators, &opening.evaluation_row) == combined_commitment
+    }
+
+    /// Opens at `point` with an inner product argument instead of sending the combined row.
+    ///
+    /// `commitment` must come from `commit` under the same `params`; panics if it does not
+    /// hold one row commitment per row. A commitment of the right shape to another polynomial
+    /// yields a proof that `verify_with_ipa` rejects.
+    pub fn open_with_ipa(
+        params: &HyraxParams,
+        commitment: &HyraxCommitment,
+        polynomial: &MultilinearPoly<Fr>,
+        point: &[Fr],
+        transcript: &mut Transcript,
+    ) -> (Fr, IpaProof) {
+        let (mut a, mut b) = evaluation_row(params, polynomial, point);
+        let value = inner_product(&a, &b);
+        let Some((combined_commitment, _)) = combined_commitment(params, commitment, point) else {
+            panic!("Commitment must hold one row commitment per row of the polynomial");
+        };
+
+        append_statement(transcript, &combined_commitment, point, value);
+
+        let u = params.inner_product_generator;
+        let mut generators = params.generators.clone();
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+
+        while a.len() > 1 {
+            let half = a.len() / 2;
+            let (a_l, a_r) = a.split_at(half);
+            let (b_l, b_r) = b.split_at(half);
+            let (g_l, g_r) = generators.split_at(half);
+
+            let l = msm(g_r, a_l) + u * inner_product(a_l, b_r);
+            let r = msm(g_l, a_r) + u * inner_product(a_r, b_l);
+            let l = l.into_affine();
+            let r = r.into_affine();
+
+            let x = round_challenge(transcript, &l, &r);
+            let x_inv = x.inverse().expect("challenge is non-zero");
+
+            a = fold(a_l, a_r, x, x_inv);
+            b = fold(b_l, b_r, x_inv, x);
+            generators = fold_generators(g_l, g_r, x_inv, x);
+            left.push(l);
+            right.push(r);
+        }
+
+        (
+            value,
+            IpaProof {
+                left,
+                right,
+                final_scalar: a[0],
+            },
+        )
+    }
+
+    pub fn verify_with_ipa(
+        params: &HyraxParams,
+        commitment: &HyraxCommitment,
+        point: &[Fr],
+        value: Fr,
+        proof: &IpaProof,
+        transcript: &mut Transcript,
+    ) -> bool {
+        let Some((combined_commitment, mut b)) = combined_commitment(params, commitment, point)
+        else {
+            return false;
+        };
+
+        let rounds = params.generators.len().ilog2() as usize;
+        if proof.left.len() != rounds || proof.right.len() != rounds {
+            return false;
+        }
+
+        append_statement(transcript, &combined_commitment, point, value);
+
+        let u = params.inner_product_generator;
+        let mut p = combined_commitment + u * value;
+        let mut generators = params.generators.clone();
+
+        for (l, r) in proof.left.iter().zip(proof.right.iter()) {
+            let x = round_challenge(transcript, l, r);
+            let x_inv = x.inverse().expect("challenge is non-zero");
+            let half = generators.len() / 2;
+
+            p += *l * x.square() + *r * x_inv.square();
+            b = fold(&b[..half], &b[half..], x_inv, x);
+            generators = fold_generators(&generators[..half], &generators[half..], x_inv, x);
+        }
+
+        p == generators[0] * proof.final_scalar + u * (proof.final_scalar * b[0])
+    }
+}
+
+/// Openings go through the inner product argument, keeping proofs logarithmic.
+impl MultilinearPCS<Fr> for Hyrax {
+    type Params = HyraxParams;
+    type Commitment = HyraxCommitment;
+    type ProverData = ();
+    type Proof = IpaProof;
+
+    fn setup<R: Rng>(num_of_vars: usize, _rng: &mut R) -> Self::Params {
+        Hyrax::setup(num_of_vars)
+    }
+
+    fn commit(
+        params: &Self::Params,
+        polynomial: &MultilinearPoly<Fr>,
+    ) -> (Self::Commitment, Self::ProverData) {
+        (Hyrax::commit(params, polynomial), ())
+    }
+
+    fn open(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        polynomial: &MultilinearPoly<Fr>,
+        _prover_data: &Self::ProverData,
+        point: &[Fr],
+        transcript: &mut Transcript,
+    ) -> (Fr, Self::Proof) {
+        Hyrax::open_with_ipa(params, commitment, polynomial, point, transcript)
+    }
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        point: &[Fr],
+        value: Fr,
+        proof: &Self::Proof,
+        transcript: &mut Transcript,
+    ) -> bool {
+        Hyrax::verify_with_ipa(params, commitment, point, value, proof, transcript)
+    }
+}
+
+/// Splits n variables into ⌊n/2⌋ row variables and ⌈n/2⌉ column variables.
+fn matrix_shape(num_of_vars: usize) -> (usize, usize) {
+    (num_of_vars / 2, num_of_vars - num_of_vars / 2)
+}
+
+/// Returns eq(r_rows, ·)ᵀM together with eq(r_cols, ·).
+fn evaluation_row(
+    params: &HyraxParams,
+    polynomial: &MultilinearPoly<Fr>,
+    point: &[Fr],
+) -> (Vec<Fr>, Vec<Fr>) {
+    if point.len() != params.num_of_vars || polynomial.num_of_vars != params.num_of_vars {
+        panic!("Invalid number of values");
+    }
+
+    let (num_row_vars, _) = matrix_shape(params.num_of_vars);
+    let (row_point, col_point) = point.split_at(num_row_vars);
+    let mut row = vec![Fr::from(0); params.generators.len()];
+
+    for (values, weight) in polynomial
+        .evaluation
+        .chunks_exact(row.len())
+        .zip(eq_evaluations(row_point))
+    {
+        for (acc, value) in row.iter_mut().zip(values.iter()) {
+            *acc += weight * value;
+        }
+    }
+
+    (row, eq_evaluations(col_point))
+}
+
+/// Returns Σ_i eq(r_rows, i)·C_i, a commitment to the combined row, together with eq(r_cols, ·).
+fn combined_commitment(
+    params: &HyraxParams,
+    commitment: &HyraxCommitment,
+    point: &[Fr],
+) -> Option<(G1Projective, Vec<Fr>)> {
+    let (num_row_vars, _) = matrix_shape(params.num_of_vars);
+    if point.len() != params.num_of_vars || commitment.row_commitments.len() != 1 << num_row_vars {
+        return None;
+    }
+
+    let (row_point, col_point) = point.split_at(num_row_vars);
+
+    Some((
+        msm(&commitment.row_commitments, &eq_evaluations(row_point)),
+        eq_evaluations(col_point),
+    ))
+}
+
+fn append_statement(
+    transcript: &mut Transcript,
+    combined_commitment: &G1Projective,
+    point: &[Fr],
+    value: Fr,
+) {
+    transcript.append(&point_to_bytes(&combined_commitment.into_affine()));
+    for coordinate in point {
+        transcript.append(&coordinate.into_bigint().to_bytes_be());
+    }
+    transcript.append(&value.into_bigint().to_bytes_be());
+}
+
+fn round_challenge(transcript: &mut Transcript, left: &G1Affine, right: &G1Affine) -> Fr {
+    transcript.append(&point_to_bytes(left));
+    transcript.append(&point_to_bytes(right));
+    transcript.get_random_challenge()
+}
+
+/// Returns low·x + high·y element-wise.
+fn fold(low: &[Fr], high: &[Fr], x: Fr, y: Fr) -> Vec<Fr> {
+    low.iter()
+        .zip(high.iter())
+        .map(|(l, h)| *l * x + *h * y)
+        .collect()
+}
+
+fn fold_generators(low: &[G1Affine], high: &[G1Affine], x: Fr, y: Fr) -> Vec<G1Affine> {
+    let folded: Vec<G1Projective> = low
+        .iter()
+        .zip(high.iter())
+        .map(|(l, h)| *l * x + *h * y)
+        .collect();
+
+    G1Projective::normalize_batch(&folded)
+}
+
+fn msm(bases: &[G1Affine], scalars: &[Fr]) -> G1Projective {
+    G1Projective::msm(bases, scalars).expect("bases and scalars have equal length")
+}
+
+fn inner_product(a: &[Fr], b: &[Fr]) -> Fr {
+    a.iter().zip(b.iter()).map(|(x, y)| *x * y).sum()
+}
+
+fn point_to_bytes(point: &G1Affine) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    point
+        .serialize_compressed(&mut bytes)
+        .expect("serializing into a Vec cannot fail");
+    bytes
+}
+
+/// Hashes `label` and `index` to a G1 point by try-and-increment on the x-coordinate.
+/// BN254 G1 has cofactor 1, so every curve point is in the prime-order subgroup.
+fn hash_to_g1(label: &[u8], index: u64) -> G1Affine {
+    (0u64..)
+        .find_map(|counter| {
+            let mut hasher = Keccak256::new();
+            hasher.update(label);
+            hasher.update(index.to_be_bytes());
+            hasher.update(counter.to_be_bytes());
+            let x = Fq::from_be_bytes_mod_order(&hasher.finalize());
+
+            G1Affine::get_point_from_x_unchecked(x, false)
+                .filter(|point| !point.is_zero() && point.is_on_curve())
+        })
+        .expect("a valid x-coordinate is found within a few attempts")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn test_setup(num_of_vars: u64) -> (MultilinearPoly<Fr>, Vec<Fr>) {
+        let polynomial = MultilinearPoly::new(
+            (0..1u64 << num_of_vars)
+                .map(|i| Fr::from(i * i + 2))
+                .collect(),
+        );
+        let point = (0..num_of_vars).map(|i| Fr::from(4 * i + 3)).collect();
+
+        (polynomial, point)
+    }
+
+    #[test]
+    fn test_generators_are_distinct() {
+        let params = Hyrax::setup(4);
+
+        assert_eq!(params.generators.len(), 4);
+        assert_ne!(params.generators[0], params.generators[1]);
+        assert_ne!(params.generators[0], params.inner_product_generator);
+    }
+
+    #[test]
+    fn test_hyrax_roundtrip() {
+        for num_of_vars in 0..6 {
+            let (polynomial, point) = test_setup(num_of_vars);
+            let params = Hyrax::setup(num_of_vars as usize);
+            let commitment = Hyrax::commit(&params, &polynomial);
+
+            let (value, opening) = Hyrax::open(&params, &polynomial, &point);
+            assert_eq!(value, polynomial.evaluate(&point));
+            assert!(Hyrax::verify(&params, &commitment, &point, value, &opening));
+        }
+    }
+
+    #[test]
+    fn test_hyrax_ipa_roundtrip() {
+        for num_of_vars in 0..6 {
+            let (polynomial, point) = test_setup(num_of_vars);
+            let params = Hyrax::setup(num_of_vars as usize);
+            let commitment = Hyrax::commit(&params, &polynomial);
+
+            let (value, proof) = Hyrax::open_with_ipa(
+                &params,
+                &commitment,
+                &polynomial,
+                &point,
+                &mut Transcript::new(),
+            );
+            assert_eq!(value, polynomial.evaluate(&point));
+            assert!(Hyrax::verify_with_ipa(
+                &params,
+                &commitment,
+                &point,
+                value,
+                &proof,
+                &mut Transcript::new()
+            ));
+        }
+    }
+
+    #[test]
+    fn test_hyrax_rejects_wrong_value() {
+        let (polynomial, point) = test_setup(4);
+        let params = Hyrax::setup(4);
+        let commitment = Hyrax::commit(&params, &polynomial);
+
+        let (value, opening) = Hyrax::open(&params, &polynomial, &point);
+        assert!(!Hyrax::verify(
+            &params,
+            &commitment,
+            &point,
+            value + Fr::from(1),
+            &opening
+        ));
+
+        let (value, proof) = Hyrax::open_with_ipa(
+            &params,
+            &commitment,
+            &polynomial,
+            &point,
+            &mut Transcript::new(),
+        );
+        assert!(!Hyrax::verify_with_ipa(
+            &params,
+            &commitment,
+            &point,
+            value + Fr::from(1),
+            &proof,
+            &mut Transcript::new()
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Commitment must hold one row commitment per row of the polynomial")]
+    fn test_open_with_ipa_rejects_mismatched_commitment_shape() {
+        let (polynomial, point) = test_setup(4);
+        let params = Hyrax::setup(4);
+        let (other_polynomial, _) = test_setup(2);
+        let commitment = Hyrax::commit(&Hyrax::setup(2), &other_polynomial);
+
+        let _ = Hyrax::open_with_ipa(
+            &params,
+            &commitment,
+            &polynomial,
+            &point,
+            &mut Transcript::new(),
+        );
+    }
+
+    #[test]
+    fn test_open_with_ipa_against_another_commitment_fails_verification() {
+        let (polynomial, point) = test_setup(4);
+        let params = Hyrax::setup(4);
+        let mut other_evaluations = polynomial.evaluation.clone();
+        other_evaluations[0] += Fr::from(1);
+        let commitment = Hyrax::commit(&params, &MultilinearPoly::new(other_evaluations));
+
+        let (value, proof) = Hyrax::open_with_ipa(
+            &params,
+            &commitment,
+            &polynomial,
+            &point,
+            &mut Transcript::new(),
+        );
+        assert!(!Hyrax::verify_with_ipa(
+            &params,
+            &commitment,
+            &point,
+            value,
+            &proof,
+            &mut Transcript::new()
+        ));
+    }
+
+    #[test]
+    fn test_hyrax_rejects_tampered_opening() {
+        let (polynomial, point) = test_setup(4);
+        let params = Hyrax::setup(4);
+        let commitment = Hyrax::commit(&params, &polynomial);
+
+        // Claim the value the tampered row evaluates to, so only the commitment check can fail.
+        let (_, mut opening) = Hyrax::open(&params, &polynomial, &point);
+        opening.evaluation_row[0] += Fr::from(1);
+        let value = inner_product(&opening.evaluation_row, &eq_evaluations(&point[2..]));
+        assert!(!Hyrax::verify(
+            &params,
+            &commitment,
+            &point,
+            value,
+            &opening
+        ));
+
+        let (value, mut proof) = Hyrax::open_with_ipa(
+            &params,
+            &commitment,
+            &polynomial,
+            &point,
+            &mut Transcript::new(),
+        );
+        proof.final_scalar += Fr::from(1);
+        assert!(!Hyrax::verify_with_ipa(
+            &params,
+            &commitment,
+            &point,
+            value,
+            &proof,
+            &mut Transcript::new()
+        ));
+    }
+}
@@ -0,0 +1,6 @@
+pub mod hyrax;
+pub mod ligero;
+pub mod merkle_tree;
+pub mod pcs;
+pub mod pst;
+pub mod trivial;
@@ -0,0 +1,473 @@
+use crate::merkle_tree::{hash_leaf, Hash, MerkleTree};
+use crate::pcs::MultilinearPCS;
+use ark_ff::{BigInteger, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::Rng;
+use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
+use multilinear_polynomial::eq_polynomial::eq_evaluations;
+use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+use std::marker::PhantomData;
+
+/// Target security level, in bits, of `LigeroParams::default`.
+pub const DEFAULT_SECURITY_BITS: usize = 128;
+
+/// Code rate and query count for the Ligero commitment.
+///
+/// With rate ρ = 1 / blowup_factor, a committed matrix whose rows are not close to codewords
+/// differs from the code on at least a (1 − ρ)/3 fraction of columns, so each spot-checked
+/// column catches it with at least that probability. The default uses ρ = 1/4 and enough
+/// queries for `DEFAULT_SECURITY_BITS` bits (309 columns); see `with_security_bits`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LigeroParams {
+    /// Reed-Solomon blowup: each row of length m is encoded to m · blowup_factor symbols.
+    /// Must be a power of 2.
+    pub blowup_factor: usize,
+    /// Number of encoded columns the verifier spot-checks.
+    pub num_column_openings: usize,
+}
+
+impl LigeroParams {
+    /// Picks the fewest column openings t with (1 − (1 − ρ)/3)^t ≤ 2^-security_bits.
+    ///
+    /// This bounds the query phase only; the random row combination adds roughly
+    /// 2^n / |F|, which is negligible for the 254-bit fields used here.
+    pub fn with_security_bits(blowup_factor: usize, security_bits: usize) -> Self {
+        let rate = 1.0 / blowup_factor as f64;
+        let escape_probability = 1.0 - (1.0 - rate) / 3.0;
+        let num_column_openings =
+            (security_bits as f64 / -escape_probability.log2()).ceil() as usize;
+
+        Self {
+            blowup_factor,
+            num_column_openings,
+        }
+    }
+
+    /// Bits of security the query phase gives against a matrix far from the code.
+    pub fn security_bits(&self) -> f64 {
+        let rate = 1.0 / self.blowup_factor as f64;
+        -(1.0 - (1.0 - rate) / 3.0).log2() * self.num_column_openings as f64
+    }
+}
+
+impl Default for LigeroParams {
+    fn default() -> Self {
+        Self::with_security_bits(4, DEFAULT_SECURITY_BITS)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct LigeroCommitment {
+    pub root: Hash,
+    pub num_of_vars: usize,
+}
+
+/// Everything the prover keeps after committing, needed to answer openings.
+#[derive(Clone, Debug)]
+pub struct LigeroProverData<F: PrimeField> {
+    pub rows: Vec<Vec<F>>,
+    pub encoded_rows: Vec<Vec<F>>,
+    pub tree: MerkleTree,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct ColumnOpening<F: PrimeField> {
+    pub index: usize,
+    pub values: Vec<F>,
+    pub path: Vec<Hash>,
+}
+
+#[derive(Clone, Debug, PartialEq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct LigeroOpening<F: PrimeField> {
+    /// γᵀM for a random γ, used for the proximity test.
+    pub combined_row: Vec<F>,
+    /// eq(r_rows, ·)ᵀM, whose inner product with eq(r_cols, ·) is the evaluation.
+    pub evaluation_row: Vec<F>,
+    pub columns: Vec<ColumnOpening<F>>,
+}
+
+/// A transparent, hash-based multilinear commitment in the style of Ligero/Brakedown.
+///
+/// The 2^n evaluations are laid out as a 2^⌊n/2⌋ × 2^⌈n/2⌉ matrix M whose rows are indexed
+/// by the leading variables, so W(r) = eq(r_rows, ·)ᵀ · M · eq(r_cols, ·). Rows are
+/// Reed-Solomon encoded and the encoded columns are committed in a Keccak Merkle tree.
+pub struct Ligero<F: PrimeField> {
+    pub params: LigeroParams,
+    _phantom: PhantomData<F>,
+}
+
+impl<F: PrimeField> Ligero<F> {
+    /// Panics if the blowup factor is not a power of 2.
+    pub fn new(params: LigeroParams) -> Self {
+        assert!(
+            params.blowup_factor.is_power_of_two(),
+            "Blowup factor must be a power of 2"
+        );
+
+        Self {
+            params,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn commit(
+        &self,
+        polynomial: &MultilinearPoly<F>,
+    ) -> (LigeroCommitment, LigeroProverData<F>) {
+        let (_, num_col_vars) = matrix_shape(polynomial.num_of_vars);
+        let rows: Vec<Vec<F>> = polynomial
+            .evaluation
+            .chunks_exact(1 << num_col_vars)
+            .map(|row| row.to_vec())
+            .collect();
+        let encoded_rows: Vec<Vec<F>> = rows.iter().map(|row| self.encode(row)).collect();
+
+        let leaves = (0..encoded_rows[0].len())
+            .map(|index| hash_leaf(&field_elements_to_bytes(&column(&encoded_rows, index))))
+            .collect();
+        let tree = MerkleTree::new(leaves);
+
+        let commitment = LigeroCommitment {
+            root: tree.root(),
+            num_of_vars: polynomial.num_of_vars,
+        };
+
+        (
+            commitment,
+            LigeroProverData {
+                rows,
+                encoded_rows,
+                tree,
+            },
+        )
+    }
+
+    /// Opens the committed polynomial at `point`, returning the evaluation and its proof.
+    pub fn open(
+        &self,
+        commitment: &LigeroCommitment,
+        prover_data: &LigeroProverData<F>,
+        point: &[F],
+        transcript: &mut Transcript,
+    ) -> (F, LigeroOpening<F>) {
+        if point.len() != commitment.num_of_vars {
+            panic!("Invalid number of values");
+        }
+
+        let (num_row_vars, _) = matrix_shape(commitment.num_of_vars);
+        let (row_point, col_point) = point.split_at(num_row_vars);
+        let evaluation_row = combine_rows(&prover_data.rows, &eq_evaluations(row_point));
+        let value = inner_product(&evaluation_row, &eq_evaluations(col_point));
+
+        append_statement(transcript, commitment, point, value);
+        let gamma = challenges(transcript, prover_data.rows.len());
+        let combined_row = combine_rows(&prover_data.rows, &gamma);
+
+        transcript.append(&field_elements_to_bytes(&combined_row));
+        transcript.append(&field_elements_to_bytes(&evaluation_row));
+
+        let codeword_length = prover_data.encoded_rows[0].len();
+        let columns = self
+            .column_indices(transcript, codeword_length)
+            .into_iter()
+            .map(|index| ColumnOpening {
+                index,
+                values: column(&prover_data.encoded_rows, index),
+                path: prover_data.tree.path(index),
+            })
+            .collect();
+
+        (
+            value,
+            LigeroOpening {
+                combined_row,
+                evaluation_row,
+                columns,
+            },
+        )
+    }
+
+    /// Checks that the committed polynomial evaluates to `value` at `point`.
+    pub fn verify(
+        &self,
+        commitment: &LigeroCommitment,
+        point: &[F],
+        value: F,
+        opening: &LigeroOpening<F>,
+        transcript: &mut Transcript,
+    ) -> bool {
+        if point.len() != commitment.num_of_vars {
+            return false;
+        }
+
+        let (num_row_vars, num_col_vars) = matrix_shape(commitment.num_of_vars);
+        let num_rows = 1 << num_row_vars;
+        let num_cols = 1 << num_col_vars;
+        if opening.combined_row.len() != num_cols || opening.evaluation_row.len() != num_cols {
+            return false;
+        }
+
+        let (row_point, col_point) = point.split_at(num_row_vars);
+        if inner_product(&opening.evaluation_row, &eq_evaluations(col_point)) != value {
+            return false;
+        }
+
+        append_statement(transcript, commitment, point, value);
+        let gamma = challenges(transcript, num_rows);
+        transcript.append(&field_elements_to_bytes(&opening.combined_row));
+        transcript.append(&field_elements_to_bytes(&opening.evaluation_row));
+
+        let codeword_length = num_cols * self.params.blowup_factor;
+        let indices = self.column_indices(transcript, codeword_length);
+        if opening.columns.len() != indices.len() {
+            return false;
+        }
+
+        let encoded_combined_row = self.encode(&opening.combined_row);
+        let encoded_evaluation_row = self.encode(&opening.evaluation_row);
+        let row_weights = eq_evaluations(row_point);
+
+        opening.columns.iter().zip(indices).all(|(column, index)| {
+            column.index == index
+                && column.values.len() == num_rows
+                && MerkleTree::verify_path(
+                    &commitment.root,
+                    &hash_leaf(&field_elements_to_bytes(&column.values)),
+                    index,
+                    &column.path,
+                    codeword_length.ilog2() as usize,
+                )
+                && inner_product(&gamma, &column.values) == encoded_combined_row[index]
+                && inner_product(&row_weights, &column.values) == encoded_evaluation_row[index]
+        })
+    }
+
+    /// Reed-Solomon encodes `message` by treating it as coefficients and evaluating at
+    /// 1, 2, ..., |message| · blowup_factor.
+    pub fn encode(&self, message: &[F]) -> Vec<F> {
+        (1..=(message.len() * self.params.blowup_factor) as u64)
+            .map(|x| {
+                let x = F::from(x);
+                message
+                    .iter()
+                    .rev()
+                    .fold(F::zero(), |acc, coeff| acc * x + coeff)
+            })
+            .collect()
+    }
+
+    fn column_indices(&self, transcript: &mut Transcript, codeword_length: usize) -> Vec<usize> {
+        challenges::<F>(transcript, self.params.num_column_openings)
+            .into_iter()
+            .map(|challenge| challenge.into_bigint().as_ref()[0] as usize % codeword_length)
+            .collect()
+    }
+}
+
+/// Splits n variables into ⌊n/2⌋ row variables and ⌈n/2⌉ column variables.
+fn matrix_shape(num_of_vars: usize) -> (usize, usize) {
+    (num_of_vars / 2, num_of_vars - num_of_vars / 2)
+}
+
+fn append_statement<F: PrimeField>(
+    transcript: &mut Transcript,
+    commitment: &LigeroCommitment,
+    point: &[F],
+    value: F,
+) {
+    transcript.append(&commitment.root);
+    transcript.append(&(commitment.num_of_vars as u64).to_be_bytes());
+    transcript.append(&field_elements_to_bytes(point));
+    transcript.append(&field_elements_to_bytes(&[value]));
+}
+
+fn challenges<F: PrimeField>(transcript: &mut Transcript, count: usize) -> Vec<F> {
+    (0..count)
+        .map(|_| transcript.get_random_challenge())
+        .collect()
+}
+
+fn column<F: PrimeField>(rows: &[Vec<F>], index: usize) -> Vec<F> {
+    rows.iter().map(|row| row[index]).collect()
+}
+
+/// Returns Σ_i weights_i · rows_i.
+fn combine_rows<F: PrimeField>(rows: &[Vec<F>], weights: &[F]) -> Vec<F> {
+    let mut combined = vec![F::zero(); rows[0].len()];
+
+    for (row, weight) in rows.iter().zip(weights.iter()) {
+        for (acc, value) in combined.iter_mut().zip(row.iter()) {
+            *acc += *weight * value;
+        }
+    }
+
+    combined
+}
+
+fn inner_product<F: PrimeField>(a: &[F], b: &[F]) -> F {
+    a.iter().zip(b.iter()).map(|(x, y)| *x * y).sum()
+}
+
+fn field_elements_to_bytes<F: PrimeField>(values: &[F]) -> Vec<u8> {
+    values
+        .iter()
+        .flat_map(|value| value.into_bigint().to_bytes_be())
+        .collect()
+}
+
+impl<F: PrimeField> MultilinearPCS<F> for Ligero<F> {
+    type Params = LigeroParams;
+    type Commitment = LigeroCommitment;
+    type ProverData = LigeroProverData<F>;
+    type Proof = LigeroOpening<F>;
+
+    /// Uses the default code rate and query count. The parameters do not depend on the
+    /// number of variables: `commit` derives the matrix shape from the polynomial itself and
+    /// records it in the commitment, so one set of parameters serves every size.
+    fn setup<R: Rng>(_num_of_vars: usize, _rng: &mut R) -> Self::Params {
+        LigeroParams::default()
+    }
+
+    fn commit(
+        params: &Self::Params,
+        polynomial: &MultilinearPoly<F>,
+    ) -> (Self::Commitment, Self::ProverData) {
+        Ligero::new(*params).commit(polynomial)
+    }
+
+    fn open(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        _polynomial: &MultilinearPoly<F>,
+        prover_data: &Self::ProverData,
+        point: &[F],
+        transcript: &mut Transcript,
+    ) -> (F, Self::Proof) {
+        Ligero::new(*params).open(commitment, prover_data, point, transcript)
+    }
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        point: &[F],
+        value: F,
+        proof: &Self::Proof,
+        transcript: &mut Transcript,
+    ) -> bool {
+        Ligero::new(*params).verify(commitment, point, value, proof, transcript)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fr;
+
+    fn test_setup(num_of_vars: u64) -> (MultilinearPoly<Fr>, Vec<Fr>) {
+        let polynomial = MultilinearPoly::new(
+            (0..1u64 << num_of_vars)
+                .map(|i| Fr::from(i * i + 7))
+                .collect(),
+        );
+        let point = (0..num_of_vars).map(|i| Fr::from(3 * i + 2)).collect();
+
+        (polynomial, point)
+    }
+
+    #[test]
+    fn test_ligero_roundtrip() {
+        let ligero = Ligero::new(LigeroParams::default());
+
+        for num_of_vars in 0..6 {
+            let (polynomial, point) = test_setup(num_of_vars);
+            let (commitment, prover_data) = ligero.commit(&polynomial);
+
+            let (value, opening) =
+                ligero.open(&commitment, &prover_data, &point, &mut Transcript::new());
+            assert_eq!(value, polynomial.evaluate(&point));
+
+            assert!(
+                ligero.verify(&commitment, &point, value, &opening, &mut Transcript::new()),
+                "Ligero opening failed for {} variables",
+                num_of_vars
+            );
+        }
+    }
+
+    #[test]
+    fn test_default_params_meet_security_target() {
+        let params = LigeroParams::default();
+
+        assert_eq!(params.blowup_factor, 4);
+        assert_eq!(params.num_column_openings, 309);
+        assert!(params.security_bits() >= DEFAULT_SECURITY_BITS as f64);
+
+        // One query fewer falls short of the target
+        let weaker = LigeroParams {
+            num_column_openings: params.num_column_openings - 1,
+            ..params
+        };
+        assert!(weaker.security_bits() < DEFAULT_SECURITY_BITS as f64);
+    }
+
+    #[test]
+    fn test_ligero_rejects_wrong_value() {
+        let ligero = Ligero::new(LigeroParams::default());
+        let (polynomial, point) = test_setup(4);
+        let (commitment, prover_data) = ligero.commit(&polynomial);
+        let (value, opening) =
+            ligero.open(&commitment, &prover_data, &point, &mut Transcript::new());
+
+        assert!(!ligero.verify(
+            &commitment,
+            &point,
+            value + Fr::from(1),
+            &opening,
+            &mut Transcript::new()
+        ));
+    }
+
+    #[test]
+    fn test_ligero_rejects_tampered_rows() {
+        let ligero = Ligero::new(LigeroParams::default());
+        let (polynomial, point) = test_setup(4);
+        let (commitment, prover_data) = ligero.commit(&polynomial);
+        let (value, opening) =
+            ligero.open(&commitment, &prover_data, &point, &mut Transcript::new());
+
+        let mut tampered = opening.clone();
+        tampered.combined_row[0] += Fr::from(1);
+        assert!(!ligero.verify(
+            &commitment,
+            &point,
+            value,
+            &tampered,
+            &mut Transcript::new()
+        ));
+
+        let mut tampered = opening.clone();
+        tampered.columns[0].values[0] += Fr::from(1);
+        assert!(!ligero.verify(
+            &commitment,
+            &point,
+            value,
+            &tampered,
+            &mut Transcript::new()
+        ));
+    }
+
+    #[test]
+    fn test_ligero_rejects_other_polynomial() {
+        let ligero = Ligero::new(LigeroParams::default());
+        let (polynomial, point) = test_setup(4);
+        let (commitment, _) = ligero.commit(&polynomial);
+
+        // Open a different polynomial against the original commitment.
+        let other = polynomial.scale(Fr::from(2));
+        let (_, other_data) = ligero.commit(&other);
+        let (value, opening) =
+            ligero.open(&commitment, &other_data, &point, &mut Transcript::new());
+
+        assert!(!ligero.verify(&commitment, &point, value, &opening, &mut Transcript::new()));
+    }
+}
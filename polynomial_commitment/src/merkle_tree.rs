@@ -0,0 +1,184 @@
+use sha3::{Digest, Keccak256};
+
+pub type Hash = [u8; 32];
+
+// Leaves and internal nodes hash under distinct prefixes, so a leaf whose bytes happen to be
+// two concatenated hashes can never be passed off as an internal node, or vice versa.
+const LEAF_TAG: u8 = 0;
+const NODE_TAG: u8 = 1;
+
+/// A binary Keccak Merkle tree over a power-of-2 number of leaves.
+#[derive(Clone, Debug)]
+pub struct MerkleTree {
+    // layers[0] holds the leaf hashes, the last layer holds the root.
+    layers: Vec<Vec<Hash>>,
+}
+
+impl MerkleTree {
+    /// Builds a tree over already hashed leaves.
+    /// Panics if the number of leaves is not a power of 2.
+    pub fn new(leaves: Vec<Hash>) -> Self {
+        assert!(
+            leaves.len().is_power_of_two(),
+            "Number of leaves must be a power of 2"
+        );
+
+        let mut layers = vec![leaves];
+        while layers.last().map_or(0, |layer| layer.len()) > 1 {
+            let next = layers
+                .last()
+                .unwrap()
+                .chunks_exact(2)
+                .map(|pair| hash_pair(&pair[0], &pair[1]))
+                .collect();
+            layers.push(next);
+        }
+
+        Self { layers }
+    }
+
+    pub fn root(&self) -> Hash {
+        self.layers.last().unwrap()[0]
+    }
+
+    /// Number of hashes in every authentication path, i.e. log2 of the number of leaves.
+    pub fn depth(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    /// Returns the sibling hashes from the leaf at `index` up to the root.
+    pub fn path(&self, index: usize) -> Vec<Hash> {
+        let mut path = Vec::with_capacity(self.layers.len() - 1);
+        let mut position = index;
+
+        for layer in &self.layers[..self.layers.len() - 1] {
+            path.push(layer[position ^ 1]);
+            position >>= 1;
+        }
+
+        path
+    }
+
+    /// Checks that `leaf` sits at `index` under `root` in a tree of the given `depth`.
+    /// Paths of any other length are rejected.
+    pub fn verify_path(
+        root: &Hash,
+        leaf: &Hash,
+        index: usize,
+        path: &[Hash],
+        depth: usize,
+    ) -> bool {
+        if path.len() != depth {
+            return false;
+        }
+
+        let mut current = *leaf;
+        let mut position = index;
+
+        for sibling in path {
+            current = if position & 1 == 0 {
+                hash_pair(&current, sibling)
+            } else {
+                hash_pair(sibling, &current)
+            };
+            position >>= 1;
+        }
+
+        position == 0 && current == *root
+    }
+}
+
+pub fn hash_leaf(bytes: &[u8]) -> Hash {
+    let mut hasher = Keccak256::new();
+    hasher.update([LEAF_TAG]);
+    hasher.update(bytes);
+    hasher.finalize().into()
+}
+
+fn hash_pair(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = Keccak256::new();
+    hasher.update([NODE_TAG]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_verifies_every_path() {
+        let leaves: Vec<Hash> = (0u8..8).map(|i| hash_leaf(&[i])).collect();
+        let tree = MerkleTree::new(leaves.clone());
+
+        for (index, leaf) in leaves.iter().enumerate() {
+            assert!(MerkleTree::verify_path(
+                &tree.root(),
+                leaf,
+                index,
+                &tree.path(index),
+                tree.depth()
+            ));
+        }
+    }
+
+    #[test]
+    fn it_rejects_a_wrong_leaf_or_index() {
+        let leaves: Vec<Hash> = (0u8..4).map(|i| hash_leaf(&[i])).collect();
+        let tree = MerkleTree::new(leaves.clone());
+
+        assert!(!MerkleTree::verify_path(
+            &tree.root(),
+            &hash_leaf(&[9]),
+            1,
+            &tree.path(1),
+            tree.depth()
+        ));
+        assert!(!MerkleTree::verify_path(
+            &tree.root(),
+            &leaves[1],
+            2,
+            &tree.path(1),
+            tree.depth()
+        ));
+    }
+
+    #[test]
+    fn it_rejects_paths_of_the_wrong_length() {
+        let leaves: Vec<Hash> = (0u8..8).map(|i| hash_leaf(&[i])).collect();
+        let tree = MerkleTree::new(leaves.clone());
+
+        // Starting one level up: an internal node presented as a leaf with a short path
+        let internal = hash_pair(&leaves[0], &leaves[1]);
+        let short_path = tree.path(0)[1..].to_vec();
+        assert!(!MerkleTree::verify_path(
+            &tree.root(),
+            &internal,
+            0,
+            &short_path,
+            tree.depth()
+        ));
+
+        let mut long_path = tree.path(3);
+        long_path.push(leaves[0]);
+        assert!(!MerkleTree::verify_path(
+            &tree.root(),
+            &leaves[3],
+            3,
+            &long_path,
+            tree.depth()
+        ));
+    }
+
+    #[test]
+    fn it_separates_leaf_and_node_hashes() {
+        let left = hash_leaf(&[1]);
+        let right = hash_leaf(&[2]);
+        let mut node_input = left.to_vec();
+        node_input.extend_from_slice(&right);
+
+        // 64 leaf bytes equal to a node's children must not hash to that node
+        assert_ne!(hash_leaf(&node_input), hash_pair(&left, &right));
+    }
+}
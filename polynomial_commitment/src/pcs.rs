@@ -0,0 +1,166 @@
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::Rng;
+use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
+use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+use polynomial_utils::polynomial::DenseUnivariatePoly;
+
+/// A commitment scheme for multilinear polynomials in evaluation form.
+///
+/// Protocols take the scheme as a type parameter instead of hard-coding one. The caller's
+/// `Transcript` is threaded through `open` and `verify`; schemes that need no challenges
+/// leave it untouched, so prover and verifier transcripts stay in sync either way.
+pub trait MultilinearPCS<F: PrimeField> {
+    /// Public parameters shared by prover and verifier.
+    type Params;
+    type Commitment: Clone + CanonicalSerialize + CanonicalDeserialize;
+    /// Whatever the prover keeps after committing to answer openings.
+    type ProverData;
+    type Proof: Clone + CanonicalSerialize + CanonicalDeserialize;
+
+    /// Transparent schemes ignore `rng`.
+    fn setup<R: Rng>(num_of_vars: usize, rng: &mut R) -> Self::Params;
+
+    fn commit(
+        params: &Self::Params,
+        polynomial: &MultilinearPoly<F>,
+    ) -> (Self::Commitment, Self::ProverData);
+
+    /// Opens at `point`, returning the evaluation and a proof of it.
+    fn open(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        polynomial: &MultilinearPoly<F>,
+        prover_data: &Self::ProverData,
+        point: &[F],
+        transcript: &mut Transcript,
+    ) -> (F, Self::Proof);
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        point: &[F],
+        value: F,
+        proof: &Self::Proof,
+        transcript: &mut Transcript,
+    ) -> bool;
+
+    /// Opens several polynomials at the same point. The default opens each in turn.
+    fn batch_open(
+        params: &Self::Params,
+        commitments: &[Self::Commitment],
+        polynomials: &[MultilinearPoly<F>],
+        prover_data: &[Self::ProverData],
+        point: &[F],
+        transcript: &mut Transcript,
+    ) -> (Vec<F>, Vec<Self::Proof>) {
+        if commitments.len() != polynomials.len() || prover_data.len() != polynomials.len() {
+            panic!("Mismatched batch sizes");
+        }
+
+        commitments
+            .iter()
+            .zip(polynomials.iter())
+            .zip(prover_data.iter())
+            .map(|((commitment, polynomial), data)| {
+                Self::open(params, commitment, polynomial, data, point, transcript)
+            })
+            .unzip()
+    }
+
+    fn batch_verify(
+        params: &Self::Params,
+        commitments: &[Self::Commitment],
+        point: &[F],
+        values: &[F],
+        proofs: &[Self::Proof],
+        transcript: &mut Transcript,
+    ) -> bool {
+        commitments.len() == values.len()
+            && proofs.len() == values.len()
+            && commitments
+                .iter()
+                .zip(values.iter())
+                .zip(proofs.iter())
+                .all(|((commitment, value), proof)| {
+                    Self::verify(params, commitment, point, *value, proof, transcript)
+                })
+    }
+}
+
+/// A commitment scheme for univariate polynomials in coefficient form, mirroring
+/// `MultilinearPCS`.
+pub trait UnivariatePCS<F: PrimeField> {
+    type Params;
+    type Commitment: Clone + CanonicalSerialize + CanonicalDeserialize;
+    type ProverData;
+    type Proof: Clone + CanonicalSerialize + CanonicalDeserialize;
+
+    /// Transparent schemes ignore `rng`.
+    fn setup<R: Rng>(max_degree: usize, rng: &mut R) -> Self::Params;
+
+    fn commit(
+        params: &Self::Params,
+        polynomial: &DenseUnivariatePoly<F>,
+    ) -> (Self::Commitment, Self::ProverData);
+
+    fn open(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        polynomial: &DenseUnivariatePoly<F>,
+        prover_data: &Self::ProverData,
+        point: F,
+        transcript: &mut Transcript,
+    ) -> (F, Self::Proof);
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        point: F,
+        value: F,
+        proof: &Self::Proof,
+        transcript: &mut Transcript,
+    ) -> bool;
+
+    /// Opens several polynomials at the same point. The default opens each in turn.
+    fn batch_open(
+        params: &Self::Params,
+        commitments: &[Self::Commitment],
+        polynomials: &[DenseUnivariatePoly<F>],
+        prover_data: &[Self::ProverData],
+        point: F,
+        transcript: &mut Transcript,
+    ) -> (Vec<F>, Vec<Self::Proof>) {
+        if commitments.len() != polynomials.len() || prover_data.len() != polynomials.len() {
+            panic!("Mismatched batch sizes");
+        }
+
+        commitments
+            .iter()
+            .zip(polynomials.iter())
+            .zip(prover_data.iter())
+            .map(|((commitment, polynomial), data)| {
+                Self::open(params, commitment, polynomial, data, point, transcript)
+            })
+            .unzip()
+    }
+
+    fn batch_verify(
+        params: &Self::Params,
+        commitments: &[Self::Commitment],
+        point: F,
+        values: &[F],
+        proofs: &[Self::Proof],
+        transcript: &mut Transcript,
+    ) -> bool {
+        commitments.len() == values.len()
+            && proofs.len() == values.len()
+            && commitments
+                .iter()
+                .zip(values.iter())
+                .zip(proofs.iter())
+                .all(|((commitment, value), proof)| {
+                    Self::verify(params, commitment, point, *value, proof, transcript)
+                })
+    }
+}
@@ -0,0 +1,259 @@
+use crate::pcs::MultilinearPCS;
+use ark_ec::pairing::Pairing;
+use ark_ec::{AffineRepr, CurveGroup, PrimeGroup, VariableBaseMSM};
+use ark_ff::One;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::Rng;
+use ark_std::UniformRand;
+use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
+use multilinear_polynomial::eq_polynomial::eq_evaluations;
+use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+use std::marker::PhantomData;
+
+/// Structured reference string for polynomials in `num_of_vars` variables.
+#[derive(Clone, Debug)]
+pub struct PstSrs<E: Pairing> {
+    pub num_of_vars: usize,
+    /// lagrange_bases[k] holds g^eq(τ_k..τ_{n-1}, x) for every x in {0,1}^(n-k).
+    pub lagrange_bases: Vec<Vec<E::G1Affine>>,
+    pub g2: E::G2Affine,
+    /// h^τ_i for each variable.
+    pub tau_g2: Vec<E::G2Affine>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PstCommitment<E: Pairing> {
+    pub commitment: E::G1Affine,
+    pub num_of_vars: usize,
+}
+
+/// One commitment per variable to the quotient q_i in f(X) − f(z) = Σ_i (X_i − z_i)·q_i(X).
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct PstProof<E: Pairing> {
+    pub quotients: Vec<E::G1Affine>,
+}
+
+/// Multilinear KZG (Papamanthou–Shi–Tamassia): constant-size commitments and n-element
+/// proofs, verified with n + 1 pairings.
+pub struct Pst<E: Pairing> {
+    _phantom: PhantomData<E>,
+}
+
+impl<E: Pairing> Pst<E> {
+    /// Samples a trapdoor τ from `rng` and derives the SRS. The trapdoor is dropped on return;
+    /// anyone who learns it can forge openings, so this is only suitable for local use.
+    pub fn setup<R: Rng>(num_of_vars: usize, rng: &mut R) -> PstSrs<E> {
+        let tau: Vec<E::ScalarField> = (0..num_of_vars)
+            .map(|_| E::ScalarField::rand(rng))
+            .collect();
+        let g1 = E::G1::generator();
+        let g2 = E::G2::generator();
+
+        let lagrange_bases = (0..=num_of_vars)
+            .map(|k| {
+                let bases: Vec<E::G1> = eq_evaluations(&tau[k..])
+                    .into_iter()
+                    .map(|scalar| g1 * scalar)
+                    .collect();
+                E::G1::normalize_batch(&bases)
+            })
+            .collect();
+        let tau_g2: Vec<E::G2> = tau.iter().map(|t| g2 * t).collect();
+
+        PstSrs {
+            num_of_vars,
+            lagrange_bases,
+            g2: g2.into_affine(),
+            tau_g2: E::G2::normalize_batch(&tau_g2),
+        }
+    }
+
+    /// Commits to f as g^f(τ), computed from the evaluations with one MSM.
+    pub fn commit(
+        srs: &PstSrs<E>,
+        polynomial: &MultilinearPoly<E::ScalarField>,
+    ) -> PstCommitment<E> {
+        if polynomial.num_of_vars != srs.num_of_vars {
+            panic!("Mismatched number of variables");
+        }
+
+        PstCommitment {
+            commitment: msm::<E>(&srs.lagrange_bases[0], &polynomial.evaluation),
+            num_of_vars: polynomial.num_of_vars,
+        }
+    }
+
+    /// Opens f at `point`, returning f(point) and the quotient commitments.
+    pub fn open(
+        srs: &PstSrs<E>,
+        polynomial: &MultilinearPoly<E::ScalarField>,
+        point: &[E::ScalarField],
+    ) -> (E::ScalarField, PstProof<E>) {
+        if point.len() != srs.num_of_vars || polynomial.num_of_vars != srs.num_of_vars {
+            panic!("Invalid number of values");
+        }
+
+        let mut current = polynomial.evaluation.clone();
+        let mut quotients = Vec::with_capacity(point.len());
+
+        // Writing f = f_0 + X_i·(f_1 − f_0) gives q_i = f_1 − f_0 over the remaining
+        // variables; f is then folded at z_i and the process repeats.
+        for (i, z_i) in point.iter().enumerate() {
+            let (low, high) = current.split_at(current.len() / 2);
+            let quotient: Vec<E::ScalarField> =
+                low.iter().zip(high.iter()).map(|(a, b)| *b - a).collect();
+            quotients.push(msm::<E>(&srs.lagrange_bases[i + 1], &quotient));
+
+            current = low
+                .iter()
+                .zip(quotient.iter())
+                .map(|(a, q)| *a + *z_i * q)
+                .collect();
+        }
+
+        (current[0], PstProof { quotients })
+    }
+
+    /// Checks e(C − g^v, h) = Π_i e(π_i, h^(τ_i − z_i)).
+    pub fn verify(
+        srs: &PstSrs<E>,
+        commitment: &PstCommitment<E>,
+        point: &[E::ScalarField],
+        value: E::ScalarField,
+        proof: &PstProof<E>,
+    ) -> bool {
+        if point.len() != srs.num_of_vars
+            || commitment.num_of_vars != srs.num_of_vars
+            || proof.quotients.len() != srs.num_of_vars
+        {
+            return false;
+        }
+
+        let g1 = E::G1::generator();
+        let lhs = commitment.commitment.into_group() - g1 * value;
+
+        let mut g1_terms: Vec<E::G1Affine> = proof.quotients.clone();
+        let mut g2_terms: Vec<E::G2Affine> = srs
+            .tau_g2
+            .iter()
+            .zip(point.iter())
+            .map(|(tau_i, z_i)| (tau_i.into_group() - srs.g2 * z_i).into_affine())
+            .collect();
+
+        // Move the left-hand side across so the whole check is a single multi-pairing.
+        g1_terms.push((-lhs).into_affine());
+        g2_terms.push(srs.g2);
+
+        E::multi_pairing(g1_terms, g2_terms).0 == E::TargetField::one()
+    }
+}
+
+/// PST needs no challenges, so the transcript is left untouched.
+impl<E: Pairing> MultilinearPCS<E::ScalarField> for Pst<E> {
+    type Params = PstSrs<E>;
+    type Commitment = PstCommitment<E>;
+    type ProverData = ();
+    type Proof = PstProof<E>;
+
+    fn setup<R: Rng>(num_of_vars: usize, rng: &mut R) -> Self::Params {
+        Pst::setup(num_of_vars, rng)
+    }
+
+    fn commit(
+        params: &Self::Params,
+        polynomial: &MultilinearPoly<E::ScalarField>,
+    ) -> (Self::Commitment, Self::ProverData) {
+        (Pst::commit(params, polynomial), ())
+    }
+
+    fn open(
+        params: &Self::Params,
+        _commitment: &Self::Commitment,
+        polynomial: &MultilinearPoly<E::ScalarField>,
+        _prover_data: &Self::ProverData,
+        point: &[E::ScalarField],
+        _transcript: &mut Transcript,
+    ) -> (E::ScalarField, Self::Proof) {
+        Pst::open(params, polynomial, point)
+    }
+
+    fn verify(
+        params: &Self::Params,
+        commitment: &Self::Commitment,
+        point: &[E::ScalarField],
+        value: E::ScalarField,
+        proof: &Self::Proof,
+        _transcript: &mut Transcript,
+    ) -> bool {
+        Pst::verify(params, commitment, point, value, proof)
+    }
+}
+
+fn msm<E: Pairing>(bases: &[E::G1Affine], scalars: &[E::ScalarField]) -> E::G1Affine {
+    E::G1::msm(bases, scalars)
+        .expect("bases and scalars have equal length")
+        .into_affine()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bls12_381::{Bls12_381, Fr};
+    use ark_std::test_rng;
+
+    fn test_setup(num_of_vars: u64) -> (MultilinearPoly<Fr>, Vec<Fr>) {
+        let polynomial = MultilinearPoly::new(
+            (0..1u64 << num_of_vars)
+                .map(|i| Fr::from(i * 3 + 1))
+                .collect(),
+        );
+        let point = (0..num_of_vars).map(|i| Fr::from(5 * i + 2)).collect();
+
+        (polynomial, point)
+    }
+
+    #[test]
+    fn test_pst_roundtrip() {
+        let mut rng = test_rng();
+
+        for num_of_vars in 0..4 {
+            let (polynomial, point) = test_setup(num_of_vars);
+            let srs = Pst::<Bls12_381>::setup(num_of_vars as usize, &mut rng);
+
+            let commitment = Pst::commit(&srs, &polynomial);
+            let (value, proof) = Pst::open(&srs, &polynomial, &point);
+
+            assert_eq!(value, polynomial.evaluate(&point));
+            assert_eq!(proof.quotients.len(), num_of_vars as usize);
+            assert!(Pst::verify(&srs, &commitment, &point, value, &proof));
+        }
+    }
+
+    #[test]
+    fn test_pst_rejects_wrong_value() {
+        let (polynomial, point) = test_setup(3);
+        let srs = Pst::<Bls12_381>::setup(3, &mut test_rng());
+        let commitment = Pst::commit(&srs, &polynomial);
+        let (value, proof) = Pst::open(&srs, &polynomial, &point);
+
+        assert!(!Pst::verify(
+            &srs,
+            &commitment,
+            &point,
+            value + Fr::from(1),
+            &proof
+        ));
+    }
+
+    #[test]
+    fn test_pst_rejects_other_polynomial() {
+        let (polynomial, point) = test_setup(3);
+        let srs = Pst::<Bls12_381>::setup(3, &mut test_rng());
+        let commitment = Pst::commit(&srs, &polynomial);
+
+        let other = polynomial.scale(Fr::from(2));
+        let (value, proof) = Pst::open(&srs, &other, &point);
+
+        assert!(!Pst::verify(&srs, &commitment, &point, value, &proof));
+    }
+}
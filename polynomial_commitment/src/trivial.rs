@@ -0,0 +1,239 @@
+use crate::pcs::{MultilinearPCS, UnivariatePCS};
+use ark_ff::PrimeField;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use ark_std::rand::Rng;
+use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
+use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+use polynomial_utils::polynomial::DenseUnivariatePoly;
+use polynomial_utils::univariate::UnivariatePolynomial;
+
+/// The whole polynomial: its evaluations (multilinear) or coefficients (univariate).
+#[derive(Clone, Debug, PartialEq, Eq, CanonicalSerialize, CanonicalDeserialize)]
+pub struct TrivialCommitment<F: PrimeField> {
+    pub values: Vec<F>,
+}
+
+/// The "commitment" protocols used before any real scheme existed: the prover sends the
+/// whole polynomial and the verifier evaluates it itself. Not hiding, not succinct.
+pub struct TrivialPCS;
+
+impl<F: PrimeField> MultilinearPCS<F> for TrivialPCS {
+    type Params = ();
+    type Commitment = TrivialCommitment<F>;
+    type ProverData = ();
+    type Proof = ();
+
+    fn setup<R: Rng>(_num_of_vars: usize, _rng: &mut R) -> Self::Params {}
+
+    fn commit(
+        _params: &Self::Params,
+        polynomial: &MultilinearPoly<F>,
+    ) -> (Self::Commitment, Self::ProverData) {
+        (
+            TrivialCommitment {
+                values: polynomial.evaluation.clone(),
+            },
+            (),
+        )
+    }
+
+    fn open(
+        _params: &Self::Params,
+        _commitment: &Self::Commitment,
+        polynomial: &MultilinearPoly<F>,
+        _prover_data: &Self::ProverData,
+        point: &[F],
+        _transcript: &mut Transcript,
+    ) -> (F, Self::Proof) {
+        (polynomial.evaluate(point), ())
+    }
+
+    fn verify(
+        _params: &Self::Params,
+        commitment: &Self::Commitment,
+        point: &[F],
+        value: F,
+        _proof: &Self::Proof,
+        _transcript: &mut Transcript,
+    ) -> bool {
+        MultilinearPoly::try_new(commitment.values.clone())
+            .and_then(|polynomial| polynomial.try_evaluate(point))
+            .is_ok_and(|evaluation| evaluation == value)
+    }
+}
+
+impl<F: PrimeField> UnivariatePCS<F> for TrivialPCS {
+    type Params = ();
+    type Commitment = TrivialCommitment<F>;
+    type ProverData = ();
+    type Proof = ();
+
+    fn setup<R: Rng>(_max_degree: usize, _rng: &mut R) -> Self::Params {}
+
+    fn commit(
+        _params: &Self::Params,
+        polynomial: &DenseUnivariatePoly<F>,
+    ) -> (Self::Commitment, Self::ProverData) {
+        (
+            TrivialCommitment {
+                values: polynomial.coefficients.clone(),
+            },
+            (),
+        )
+    }
+
+    fn open(
+        _params: &Self::Params,
+        _commitment: &Self::Commitment,
+        polynomial: &DenseUnivariatePoly<F>,
+        _prover_data: &Self::ProverData,
+        point: F,
+        _transcript: &mut Transcript,
+    ) -> (F, Self::Proof) {
+        (polynomial.evaluate(point), ())
+    }
+
+    fn verify(
+        _params: &Self::Params,
+        commitment: &Self::Commitment,
+        point: F,
+        value: F,
+        _proof: &Self::Proof,
+        _transcript: &mut Transcript,
+    ) -> bool {
+        DenseUnivariatePoly::new(commitment.values.clone()).evaluate(point) == value
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hyrax::Hyrax;
+    use crate::ligero::Ligero;
+    use crate::pst::Pst;
+    use ark_bls12_381::Bls12_381;
+    use ark_bn254::Fr;
+    use ark_std::test_rng;
+
+    /// Runs commit, open, verify, batch_open and batch_verify through the trait only, as a
+    /// protocol generic over the scheme would.
+    fn check_scheme<F: PrimeField, P: MultilinearPCS<F>>() {
+        let params = P::setup(3, &mut test_rng());
+        let polynomials: Vec<MultilinearPoly<F>> = (1..=2u64)
+            .map(|k| MultilinearPoly::new((0..8u64).map(|i| F::from(k * i + 3)).collect()))
+            .collect();
+        let point: Vec<F> = vec![F::from(5u64), F::from(7u64), F::from(11u64)];
+
+        let (commitments, prover_data): (Vec<_>, Vec<_>) = polynomials
+            .iter()
+            .map(|polynomial| P::commit(&params, polynomial))
+            .unzip();
+
+        let (value, proof) = P::open(
+            &params,
+            &commitments[0],
+            &polynomials[0],
+            &prover_data[0],
+            &point,
+            &mut Transcript::new(),
+        );
+        assert_eq!(value, polynomials[0].evaluate(&point));
+        assert!(P::verify(
+            &params,
+            &commitments[0],
+            &point,
+            value,
+            &proof,
+            &mut Transcript::new()
+        ));
+        assert!(!P::verify(
+            &params,
+            &commitments[0],
+            &point,
+            value + F::one(),
+            &proof,
+            &mut Transcript::new()
+        ));
+
+        // Commitments and proofs survive a serialization roundtrip.
+        let mut bytes = Vec::new();
+        proof.serialize_compressed(&mut bytes).unwrap();
+        let decoded = P::Proof::deserialize_compressed(&bytes[..]).unwrap();
+        let mut bytes = Vec::new();
+        commitments[0].serialize_compressed(&mut bytes).unwrap();
+        let commitment = P::Commitment::deserialize_compressed(&bytes[..]).unwrap();
+        assert!(P::verify(
+            &params,
+            &commitment,
+            &point,
+            value,
+            &decoded,
+            &mut Transcript::new()
+        ));
+
+        let (values, proofs) = P::batch_open(
+            &params,
+            &commitments,
+            &polynomials,
+            &prover_data,
+            &point,
+            &mut Transcript::new(),
+        );
+        assert!(P::batch_verify(
+            &params,
+            &commitments,
+            &point,
+            &values,
+            &proofs,
+            &mut Transcript::new()
+        ));
+        assert!(!P::batch_verify(
+            &params,
+            &commitments,
+            &point,
+            &[values[1], values[0]],
+            &proofs,
+            &mut Transcript::new()
+        ));
+    }
+
+    #[test]
+    fn test_multilinear_schemes() {
+        check_scheme::<Fr, TrivialPCS>();
+        check_scheme::<Fr, Ligero<Fr>>();
+        check_scheme::<Fr, Hyrax>();
+        check_scheme::<ark_bls12_381::Fr, Pst<Bls12_381>>();
+    }
+
+    #[test]
+    fn test_trivial_univariate() {
+        let polynomial = DenseUnivariatePoly::new(vec![Fr::from(1), Fr::from(2), Fr::from(3)]);
+        let (commitment, data) = <TrivialPCS as UnivariatePCS<Fr>>::commit(&(), &polynomial);
+
+        let (value, proof) = <TrivialPCS as UnivariatePCS<Fr>>::open(
+            &(),
+            &commitment,
+            &polynomial,
+            &data,
+            Fr::from(2),
+            &mut Transcript::new(),
+        );
+        assert_eq!(value, Fr::from(17));
+        assert!(<TrivialPCS as UnivariatePCS<Fr>>::verify(
+            &(),
+            &commitment,
+            Fr::from(2),
+            value,
+            &proof,
+            &mut Transcript::new()
+        ));
+        assert!(!<TrivialPCS as UnivariatePCS<Fr>>::verify(
+            &(),
+            &commitment,
+            Fr::from(3),
+            value,
+            &proof,
+            &mut Transcript::new()
+        ));
+    }
+}
@@ -1,7 +1,7 @@
 use ark_bn254::Fr;
 use ark_ff::PrimeField as _;
 use ark_std::UniformRand;
-use polynomial_utils::{interpolation::lagrange_interpolate, polynomial::DenseUnivariatePoly};
+use polynomial_utils::{barycentric::BarycentricWeights, polynomial::DenseUnivariatePoly};
 
 pub fn encode_location(lt: f64, ln: f64) -> (Fr, Fr) {
     // scale the longitude and latitude to integers
@@ -17,8 +17,8 @@ pub fn encode_location(lt: f64, ln: f64) -> (Fr, Fr) {
 
 pub fn decode_location(lt: f64, ln: f64) -> (f64, f64) {
     // scale the integers to floating point numbers
-    let lt = lt as f64 / 1e3;
-    let ln = ln as f64 / 1e3;
+    let lt = lt / 1e3;
+    let ln = ln / 1e3;
 
     (lt, ln)
 }
@@ -41,19 +41,14 @@ pub fn generate_ln_polynomial(ln_secret: Fr) -> Vec<Fr> {
 }
 
 pub fn generate_shares(poly: &DenseUnivariatePoly<Fr>, num_shares: usize) -> Vec<(Fr, Fr)> {
-    let mut shares = Vec::new();
+    let x_values: Vec<Fr> = (1..=num_shares as u64).map(Fr::from).collect();
+    let y_values = poly.evaluate_many(&x_values);
 
-    for i in 1..=num_shares {
-        let x = Fr::from(i as u64);
-        let y = poly.evaluate(x);
-        shares.push((x, y));
-    }
-
-    shares
+    x_values.into_iter().zip(y_values).collect()
 }
 
 fn distribute_shares(shares: Vec<(Fr, Fr)>) {
-    let friends = vec![
+    let friends = [
         "psychemist",
         "kitchens",
         "wolfie",
@@ -73,13 +68,11 @@ fn distribute_shares(shares: Vec<(Fr, Fr)>) {
 }
 
 fn reconstruct_secret(shares: &[(Fr, Fr)]) -> Fr {
-    // Use the lagrange interpolation function from the crate
+    // Only f(0) is needed, so evaluate the interpolant directly instead of rebuilding it
     let x_values: Vec<Fr> = shares.iter().map(|(x, _)| *x).collect();
     let y_values: Vec<Fr> = shares.iter().map(|(_, y)| *y).collect();
 
-    let poly = lagrange_interpolate(&x_values, &y_values);
-
-    poly.evaluate(Fr::from(0))
+    BarycentricWeights::new(&x_values).evaluate(&y_values, Fr::from(0))
 }
 
 fn primefield_to_f64(field_element: &Fr) -> f64 {
@@ -114,7 +107,7 @@ fn main() {
     let combined_shares: Vec<(Fr, Fr)> = lt_shares
         .clone()
         .into_iter()
-        .zip(ln_shares.clone().into_iter())
+        .zip(ln_shares.clone())
         .map(|(lt_share, ln_share)| (lt_share.1, ln_share.1)) // Using .1 to get the y-values
         .collect();
 
@@ -0,0 +1,183 @@
+use crate::sumcheck::{Prover, SumcheckProof, Verifier};
+use ark_ff::PrimeField;
+use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+
+/// The ways a malicious prover can deviate from an honest proof.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Attack {
+    /// Claims a different sum and patches the first round polynomial to match it.
+    WrongClaimedSum,
+    /// Changes one evaluation of the round polynomial at the given round.
+    TamperedRoundPolynomial(usize),
+    /// Sends a round polynomial of the wrong size at the given round.
+    MalformedRoundPolynomial(usize),
+    /// Sends the round polynomials in reverse order.
+    ReorderedRounds,
+    /// Drops the last round polynomial.
+    TruncatedProof,
+    /// Replaces the initial polynomial with a different one that has the same sum.
+    SwappedInitialPolynomial,
+    /// Moves weight between the evaluations of the last round polynomial, keeping its sum.
+    /// Every round check still passes, so only the final evaluation check can catch it.
+    SumPreservingLastRound,
+}
+
+/// Lists every attack applicable to a proof with `num_rounds` rounds.
+pub fn attacks_for(num_rounds: usize) -> Vec<Attack> {
+    let mut attacks = vec![Attack::WrongClaimedSum];
+
+    for round in 0..num_rounds {
+        attacks.push(Attack::TamperedRoundPolynomial(round));
+        attacks.push(Attack::MalformedRoundPolynomial(round));
+    }
+
+    if num_rounds > 1 {
+        attacks.push(Attack::ReorderedRounds);
+    }
+
+    if num_rounds > 0 {
+        attacks.push(Attack::TruncatedProof);
+        attacks.push(Attack::SwappedInitialPolynomial);
+        attacks.push(Attack::SumPreservingLastRound);
+    }
+
+    attacks
+}
+
+/// Produces a malicious proof by applying `attack` to an honest one.
+pub fn tamper<F: PrimeField>(honest_proof: &SumcheckProof<F>, attack: Attack) -> SumcheckProof<F> {
+    let mut proof = honest_proof.clone();
+
+    match attack {
+        Attack::WrongClaimedSum => {
+            proof.initial_claimed_sum += F::one();
+            if let Some(first_round) = proof.round_univariate_polynomials.first_mut() {
+                first_round.evaluation[1] += F::one();
+            }
+        }
+        Attack::TamperedRoundPolynomial(round) => {
+            proof.round_univariate_polynomials[round].evaluation[0] += F::one();
+        }
+        Attack::MalformedRoundPolynomial(round) => {
+            let mut evaluation = proof.round_univariate_polynomials[round].evaluation.clone();
+            evaluation.extend_from_slice(&[F::zero(), F::zero()]);
+            proof.round_univariate_polynomials[round] = MultilinearPoly::new(evaluation);
+        }
+        Attack::ReorderedRounds => {
+            proof.round_univariate_polynomials.reverse();
+        }
+        Attack::TruncatedProof => {
+            proof.round_univariate_polynomials.pop();
+        }
+        Attack::SwappedInitialPolynomial => {
+            let mut evaluation = proof.initial_polynomial.evaluations().to_vec();
+            evaluation[0] += F::one();
+            evaluation[1] -= F::one();
+            proof.initial_polynomial = MultilinearPoly::new(evaluation).into_shared();
+        }
+        Attack::SumPreservingLastRound => {
+            let last_round = proof
+                .round_univariate_polynomials
+                .last_mut()
+                .expect("attack needs at least one round");
+            last_round.evaluation[0] += F::one();
+            last_round.evaluation[1] -= F::one();
+        }
+    }
+
+    proof
+}
+
+/// Runs the honest prover on `evaluations`, checks that its proof is accepted, then asserts
+/// that `Verifier::verify` rejects every applicable attack. Attacks that leave the proof
+/// unchanged (e.g. reordering identical rounds) are skipped.
+pub fn assert_rejects_malicious_proofs<F: PrimeField>(evaluations: &[F]) {
+    let honest_proof = Prover::init(evaluations).prove();
+    assert!(
+        Verifier::init().verify(honest_proof.clone()),
+        "Honest proof was rejected"
+    );
+
+    for attack in attacks_for(honest_proof.round_univariate_polynomials.len()) {
+        let malicious_proof = tamper(&honest_proof, attack);
+        if malicious_proof == honest_proof {
+            continue;
+        }
+
+        assert!(
+            !Verifier::init().verify(malicious_proof),
+            "Verifier accepted a malicious proof: {:?}",
+            attack
+        );
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sumcheck::{field_element_to_bytes, verify_rounds};
+    use ark_bn254::Fq;
+    use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
+
+    // Replays the verifier's round checks without the final evaluation check.
+    fn passes_round_checks(proof: &SumcheckProof<Fq>) -> bool {
+        let mut transcript = Transcript::new();
+        transcript.append(&proof.initial_polynomial.convert_to_bytes());
+        transcript.append(&field_element_to_bytes(proof.initial_claimed_sum));
+
+        verify_rounds(
+            &mut transcript,
+            proof.initial_claimed_sum,
+            &proof.round_univariate_polynomials,
+        )
+        .is_some()
+    }
+
+    #[test]
+    fn test_rejects_malicious_proofs() {
+        let evaluated_values = vec![Fq::from(0), Fq::from(0), Fq::from(3), Fq::from(8)];
+        assert_rejects_malicious_proofs(&evaluated_values);
+    }
+
+    #[test]
+    fn test_rejects_malicious_proofs_over_larger_hypercubes() {
+        for num_of_vars in 1..=5u64 {
+            let evaluated_values: Vec<Fq> = (0..1u64 << num_of_vars)
+                .map(|i| Fq::from(7 * i + num_of_vars))
+                .collect();
+            assert_rejects_malicious_proofs(&evaluated_values);
+        }
+    }
+
+    #[test]
+    fn test_final_evaluation_check_rejects_sum_preserving_last_round() {
+        for num_of_vars in 1..=5u64 {
+            let evaluated_values: Vec<Fq> = (0..1u64 << num_of_vars)
+                .map(|i| Fq::from(7 * i + num_of_vars))
+                .collect();
+            let honest_proof = Prover::init(&evaluated_values).prove();
+            let malicious_proof = tamper(&honest_proof, Attack::SumPreservingLastRound);
+
+            assert!(passes_round_checks(&malicious_proof));
+            assert!(
+                !Verifier::init().verify(malicious_proof),
+                "Final evaluation check accepted a tampered last round with {num_of_vars} variables"
+            );
+        }
+    }
+
+    #[test]
+    fn test_attacks_for_single_round() {
+        assert_eq!(
+            attacks_for(1),
+            vec![
+                Attack::WrongClaimedSum,
+                Attack::TamperedRoundPolynomial(0),
+                Attack::MalformedRoundPolynomial(0),
+                Attack::TruncatedProof,
+                Attack::SwappedInitialPolynomial,
+                Attack::SumPreservingLastRound,
+            ]
+        );
+    }
+}
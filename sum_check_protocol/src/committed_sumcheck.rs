@@ -0,0 +1,215 @@
+use crate::sumcheck::{field_element_to_bytes, split_polynomial_and_sum_each, verify_rounds};
+use ark_ff::PrimeField;
+use ark_serialize::CanonicalSerialize;
+use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
+use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+use polynomial_commitment::pcs::MultilinearPCS;
+
+/// A sumcheck proof in which the verifier never sees the evaluation table.
+///
+/// The prover commits to the polynomial with the scheme `P`; the final claim
+/// W(r_1, ..., r_n) is then backed by an opening of that commitment instead of the
+/// verifier evaluating the table itself.
+pub struct CommittedSumcheckProof<F: PrimeField, P: MultilinearPCS<F>> {
+    pub commitment: P::Commitment,
+    pub initial_claimed_sum: F,
+    pub round_univariate_polynomials: Vec<MultilinearPoly<F>>,
+    pub final_evaluation: F,
+    pub opening: P::Proof,
+}
+
+pub struct CommittedProver<F: PrimeField, P: MultilinearPCS<F>> {
+    pub params: P::Params,
+    pub polynomial: MultilinearPoly<F>,
+    pub transcript: Transcript,
+}
+
+impl<F: PrimeField, P: MultilinearPCS<F>> CommittedProver<F, P> {
+    pub fn init(params: P::Params, polynomial: MultilinearPoly<F>) -> Self {
+        Self {
+            params,
+            polynomial,
+            transcript: Transcript::new(),
+        }
+    }
+
+    /// Commits to the polynomial, runs the rounds and opens the commitment at the challenges.
+    pub fn prove(&mut self) -> CommittedSumcheckProof<F, P> {
+        // Each call produces a fresh proof, so start from a clean transcript.
+        self.transcript = Transcript::new();
+
+        let (commitment, prover_data) = P::commit(&self.params, &self.polynomial);
+        let initial_claimed_sum: F = self.polynomial.evaluation.iter().sum();
+
+        self.transcript.append(&commitment_to_bytes(&commitment));
+        self.transcript
+            .append(&field_element_to_bytes(initial_claimed_sum));
+
+        let mut round_univariate_polynomials = Vec::with_capacity(self.polynomial.num_of_vars);
+        let mut challenges = Vec::with_capacity(self.polynomial.num_of_vars);
+        // The first round reads the committed polynomial in place; only folded tables are owned.
+        let mut folded_polynomial: Option<MultilinearPoly<F>> = None;
+
+        for _ in 0..self.polynomial.num_of_vars {
+            let current_polynomial = folded_polynomial.as_ref().unwrap_or(&self.polynomial);
+            let univariate_polynomial = MultilinearPoly::new(split_polynomial_and_sum_each(
+                &current_polynomial.evaluation,
+            ));
+            self.transcript
+                .append(&univariate_polynomial.convert_to_bytes());
+            round_univariate_polynomials.push(univariate_polynomial);
+
+            let random_challenge: F = self.transcript.get_random_challenge();
+            challenges.push(random_challenge);
+            folded_polynomial = Some(current_polynomial.partial_evaluate(0, &random_challenge));
+        }
+
+        let (final_evaluation, opening) = P::open(
+            &self.params,
+            &commitment,
+            &self.polynomial,
+            &prover_data,
+            &challenges,
+            &mut self.transcript,
+        );
+
+        CommittedSumcheckProof {
+            commitment,
+            initial_claimed_sum,
+            round_univariate_polynomials,
+            final_evaluation,
+            opening,
+        }
+    }
+}
+
+/// Verifies proofs about a committed polynomial in `num_of_vars` variables. The verifier fixes
+/// the number of variables itself rather than trusting the proof or the commitment for it.
+pub struct CommittedVerifier<F: PrimeField, P: MultilinearPCS<F>> {
+    pub params: P::Params,
+    pub num_of_vars: usize,
+    pub transcript: Transcript,
+}
+
+impl<F: PrimeField, P: MultilinearPCS<F>> CommittedVerifier<F, P> {
+    pub fn init(params: P::Params, num_of_vars: usize) -> Self {
+        Self {
+            params,
+            num_of_vars,
+            transcript: Transcript::new(),
+        }
+    }
+
+    /// Checks there is one round per variable and each round is consistent, then that the
+    /// opening proves W(challenges) = final claim.
+    pub fn verify(&mut self, proof: &CommittedSumcheckProof<F, P>) -> bool {
+        if proof.round_univariate_polynomials.len() != self.num_of_vars {
+            return false;
+        }
+
+        self.transcript
+            .append(&commitment_to_bytes(&proof.commitment));
+        self.transcript
+            .append(&field_element_to_bytes(proof.initial_claimed_sum));
+
+        let Some((challenges, current_claim_sum)) = verify_rounds(
+            &mut self.transcript,
+            proof.initial_claimed_sum,
+            &proof.round_univariate_polynomials,
+        ) else {
+            return false;
+        };
+
+        current_claim_sum == proof.final_evaluation
+            && P::verify(
+                &self.params,
+                &proof.commitment,
+                &challenges,
+                proof.final_evaluation,
+                &proof.opening,
+                &mut self.transcript,
+            )
+    }
+}
+
+fn commitment_to_bytes<C: CanonicalSerialize>(commitment: &C) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    commitment
+        .serialize_compressed(&mut bytes)
+        .expect("serializing into a Vec cannot fail");
+    bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::{Fq, Fr};
+    use polynomial_commitment::hyrax::Hyrax;
+    use polynomial_commitment::ligero::Ligero;
+
+    fn sample_polynomial<F: PrimeField>() -> MultilinearPoly<F> {
+        MultilinearPoly::new((0..16u64).map(|i| F::from(i * i + 3)).collect())
+    }
+
+    fn prove_and_verify<F: PrimeField, P: MultilinearPCS<F>>(
+        params: P::Params,
+        verifier_params: P::Params,
+    ) -> (CommittedSumcheckProof<F, P>, bool) {
+        let mut prover = CommittedProver::<F, P>::init(params, sample_polynomial());
+        let proof = prover.prove();
+        let accepted = CommittedVerifier::<F, P>::init(verifier_params, 4).verify(&proof);
+
+        (proof, accepted)
+    }
+
+    #[test]
+    fn test_committed_sumcheck_with_ligero() {
+        let mut rng = rand::thread_rng();
+        let params = Ligero::<Fq>::setup(4, &mut rng);
+        let (proof, accepted) = prove_and_verify::<Fq, Ligero<Fq>>(params, params);
+
+        assert!(accepted, "Ligero-backed sumcheck verification failed");
+        assert_eq!(
+            proof.initial_claimed_sum,
+            sample_polynomial::<Fq>().evaluation.iter().sum()
+        );
+    }
+
+    #[test]
+    fn test_committed_sumcheck_with_hyrax() {
+        let (_, accepted) = prove_and_verify::<Fr, Hyrax>(Hyrax::setup(4), Hyrax::setup(4));
+
+        assert!(accepted, "Hyrax-backed sumcheck verification failed");
+    }
+
+    #[test]
+    fn test_committed_sumcheck_rejects_wrong_final_evaluation() {
+        let params = Hyrax::setup(4);
+        let mut prover = CommittedProver::<Fr, Hyrax>::init(params.clone(), sample_polynomial());
+        let mut proof = prover.prove();
+        proof.final_evaluation += Fr::from(1);
+
+        assert!(!CommittedVerifier::<Fr, Hyrax>::init(params, 4).verify(&proof));
+    }
+
+    #[test]
+    fn test_committed_sumcheck_rejects_wrong_round_count() {
+        let params = Hyrax::setup(4);
+        let mut prover = CommittedProver::<Fr, Hyrax>::init(params.clone(), sample_polynomial());
+        let proof = prover.prove();
+
+        assert!(!CommittedVerifier::<Fr, Hyrax>::init(params.clone(), 3).verify(&proof));
+        assert!(!CommittedVerifier::<Fr, Hyrax>::init(params, 5).verify(&proof));
+    }
+
+    #[test]
+    fn test_committed_prover_can_prove_twice() {
+        let params = Hyrax::setup(4);
+        let mut prover = CommittedProver::<Fr, Hyrax>::init(params.clone(), sample_polynomial());
+        let first = prover.prove();
+        let second = prover.prove();
+
+        assert!(CommittedVerifier::<Fr, Hyrax>::init(params.clone(), 4).verify(&first));
+        assert!(CommittedVerifier::<Fr, Hyrax>::init(params, 4).verify(&second));
+    }
+}
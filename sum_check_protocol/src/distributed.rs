@@ -0,0 +1,362 @@
+use crate::sumcheck::{field_element_to_bytes, split_polynomial_and_sum_each, SumcheckProof};
+use ark_ff::PrimeField;
+use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
+use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+use multilinear_polynomial::multilinear_view::{MultilinearView, SharedMultilinear};
+use std::sync::Arc;
+use std::thread;
+
+/// A worker holding one slice of the evaluation table.
+///
+/// The hypercube is partitioned on its trailing variables: worker `i` owns every
+/// evaluation whose last `log2(workers)` index bits equal `i`. The leading variables
+/// are therefore local to each worker and can be folded without communication.
+pub trait SumcheckWorker<F: PrimeField>: Send {
+    /// Returns the number of variables still held locally.
+    fn number_of_variables(&self) -> usize;
+
+    /// Returns the partial round sums of the local slice at 0 and 1.
+    fn round_sums(&self) -> Vec<F>;
+
+    /// Fixes the current leading variable to the verifier's challenge.
+    fn fold(&mut self, challenge: &F);
+
+    /// Returns the single value left once every local variable has been folded.
+    fn folded_value(&self) -> F;
+}
+
+/// An in-process worker backed by its own slice of the evaluation table.
+pub struct LocalWorker<F: PrimeField> {
+    pub polynomial: MultilinearPoly<F>,
+}
+
+impl<F: PrimeField> SumcheckWorker<F> for LocalWorker<F> {
+    fn number_of_variables(&self) -> usize {
+        self.polynomial.number_of_variables()
+    }
+
+    fn round_sums(&self) -> Vec<F> {
+        split_polynomial_and_sum_each(&self.polynomial.evaluation)
+    }
+
+    fn fold(&mut self, challenge: &F) {
+        self.polynomial = self.polynomial.partial_evaluate(0, challenge);
+    }
+
+    fn folded_value(&self) -> F {
+        assert_eq!(
+            self.polynomial.number_of_variables(),
+            0,
+            "Worker still has unfolded variables"
+        );
+        self.polynomial.evaluation[0]
+    }
+}
+
+/// Splits an evaluation table into `num_workers` slices partitioned on the trailing variables.
+/// Panics if `num_workers` is not a power of 2 or exceeds the table size.
+pub fn partition_evaluations<F: PrimeField>(
+    evaluations: &[F],
+    num_workers: usize,
+) -> Vec<LocalWorker<F>> {
+    assert!(
+        num_workers.is_power_of_two() && num_workers <= evaluations.len(),
+        "Number of workers must be a power of 2 no larger than the evaluation table"
+    );
+
+    (0..num_workers)
+        .map(|worker| {
+            let slice = evaluations
+                .iter()
+                .skip(worker)
+                .step_by(num_workers)
+                .copied()
+                .collect();
+
+            LocalWorker {
+                polynomial: MultilinearPoly::new(slice),
+            }
+        })
+        .collect()
+}
+
+/// Coordinates a set of workers to produce a standard `SumcheckProof`.
+///
+/// Rounds over the leading variables are computed by the workers in parallel and
+/// combined by the coordinator. Once those are exhausted, the `log2(workers)` folded
+/// values are gathered and the remaining rounds run centrally.
+pub struct DistributedProver<F: PrimeField, W: SumcheckWorker<F>> {
+    pub initial_polynomial: SharedMultilinear<F>,
+    pub initial_claimed_sum: F,
+    pub transcript: Transcript,
+    pub workers: Vec<W>,
+    pub round_univariate_polynomials: Vec<MultilinearPoly<F>>,
+    pub field_multiplications: usize,
+    // Workers are folded in place, so their slices are gone after the first proof.
+    proved: bool,
+}
+
+impl<F: PrimeField> DistributedProver<F, LocalWorker<F>> {
+    /// Initializes a prover that splits the evaluation table across `num_workers` local workers.
+    /// Panics if the length of evaluated_values is not a power of 2.
+    pub fn init(multilinear_polynomial_evaluation: &[F], num_workers: usize) -> Self {
+        assert!(
+            multilinear_polynomial_evaluation.len().is_power_of_two(),
+            "Polynomial evaluation length must be a power of 2"
+        );
+        let workers = partition_evaluations(multilinear_polynomial_evaluation, num_workers);
+        let polynomial = MultilinearView::new(Arc::from(multilinear_polynomial_evaluation));
+
+        Self::with_workers(polynomial, workers)
+    }
+}
+
+impl<F: PrimeField, W: SumcheckWorker<F>> DistributedProver<F, W> {
+    /// Builds a coordinator around already partitioned workers.
+    /// Panics if the worker count or slice sizes do not fit the initial polynomial. Whether
+    /// the slices really partition its table is checked by `prove` once the workers report
+    /// their first round sums.
+    pub fn with_workers(initial_polynomial: SharedMultilinear<F>, workers: Vec<W>) -> Self {
+        assert!(!workers.is_empty(), "At least one worker is required");
+        assert!(
+            workers.len().is_power_of_two(),
+            "Number of workers must be a power of 2"
+        );
+        assert!(
+            workers.len() <= initial_polynomial.evaluations().len(),
+            "Number of workers must not exceed the evaluation table size"
+        );
+        let local_variables =
+            initial_polynomial.number_of_variables() - workers.len().ilog2() as usize;
+        assert!(
+            workers
+                .iter()
+                .all(|worker| worker.number_of_variables() == local_variables),
+            "Workers must each hold an equal slice of the polynomial"
+        );
+
+        Self {
+            initial_claimed_sum: initial_polynomial.sum(),
+            initial_polynomial,
+            transcript: Transcript::new(),
+            workers,
+            round_univariate_polynomials: Vec::new(),
+            field_multiplications: 0,
+            proved: false,
+        }
+    }
+
+    /// Generates a Sumcheck proof identical to the one produced by a single `Prover`.
+    /// Panics if called a second time, since workers are folded in place, or if the workers'
+    /// values do not add up to the claimed sum.
+    pub fn prove(&mut self) -> SumcheckProof<F> {
+        assert!(!self.proved, "DistributedProver can only prove once");
+        self.proved = true;
+
+        self.transcript
+            .append(&self.initial_polynomial.convert_to_bytes());
+        self.transcript
+            .append(&field_element_to_bytes(self.initial_claimed_sum));
+
+        let local_rounds = self.workers[0].number_of_variables();
+
+        for round in 0..local_rounds {
+            let partial_sums = run_on_workers(&mut self.workers, |worker| worker.round_sums());
+
+            let mut univariate_polynomial_values = vec![F::zero(); 2];
+            for partial_sum in partial_sums {
+                univariate_polynomial_values[0] += partial_sum[0];
+                univariate_polynomial_values[1] += partial_sum[1];
+            }
+            if round == 0 {
+                self.assert_workers_hold_claimed_sum(univariate_polynomial_values.iter().sum());
+            }
+
+            let random_challenge = self.commit_round(univariate_polynomial_values);
+            // Folding costs one multiplication per pair of evaluations on every worker.
+            self.field_multiplications += self
+                .workers
+                .iter()
+                .map(|worker| (1 << worker.number_of_variables()) / 2)
+                .sum::<usize>();
+            run_on_workers(&mut self.workers, |worker| worker.fold(&random_challenge));
+        }
+
+        let gathered_values = self
+            .workers
+            .iter()
+            .map(|worker| worker.folded_value())
+            .collect();
+        let mut current_polynomial = MultilinearPoly::new(gathered_values);
+        if local_rounds == 0 {
+            self.assert_workers_hold_claimed_sum(current_polynomial.evaluation.iter().sum());
+        }
+
+        for _ in 0..current_polynomial.number_of_variables() {
+            let univariate_polynomial_values =
+                split_polynomial_and_sum_each(&current_polynomial.evaluation);
+
+            let random_challenge = self.commit_round(univariate_polynomial_values);
+            self.field_multiplications += current_polynomial.evaluation.len() / 2;
+            current_polynomial = current_polynomial.partial_evaluate(0, &random_challenge);
+        }
+
+        SumcheckProof {
+            initial_polynomial: self.initial_polynomial.clone(),
+            initial_claimed_sum: self.initial_claimed_sum,
+            round_univariate_polynomials: self.round_univariate_polynomials.clone(),
+        }
+    }
+
+    fn assert_workers_hold_claimed_sum(&self, workers_sum: F) {
+        assert!(
+            workers_sum == self.initial_claimed_sum,
+            "Worker evaluations must sum to the claimed sum of the initial polynomial"
+        );
+    }
+
+    /// Records a round polynomial in the transcript and returns the verifier's challenge.
+    fn commit_round(&mut self, univariate_polynomial_values: Vec<F>) -> F {
+        let univariate_polynomial = MultilinearPoly::new(univariate_polynomial_values);
+        self.transcript
+            .append(&univariate_polynomial.convert_to_bytes());
+        self.round_univariate_polynomials
+            .push(univariate_polynomial);
+
+        self.transcript.get_random_challenge()
+    }
+}
+
+/// Runs `task` on every worker in its own thread and collects the results in worker order.
+fn run_on_workers<F, W, R, T>(workers: &mut [W], task: T) -> Vec<R>
+where
+    F: PrimeField,
+    W: SumcheckWorker<F>,
+    R: Send,
+    T: Fn(&mut W) -> R + Sync,
+{
+    thread::scope(|scope| {
+        let handles: Vec<_> = workers
+            .iter_mut()
+            .map(|worker| scope.spawn(|| task(worker)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("Sumcheck worker panicked"))
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::sumcheck::{Prover, Verifier};
+    use ark_bn254::Fq;
+
+    fn sample_evaluations() -> Vec<Fq> {
+        (0..16u64).map(|i| Fq::from(i * i + 3)).collect()
+    }
+
+    #[test]
+    fn test_partition_evaluations() {
+        let evaluated_values = sample_evaluations();
+        let workers = partition_evaluations(&evaluated_values, 4);
+
+        assert_eq!(workers.len(), 4);
+        assert_eq!(
+            workers[1].polynomial.evaluation,
+            vec![
+                evaluated_values[1],
+                evaluated_values[5],
+                evaluated_values[9],
+                evaluated_values[13]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_distributed_matches_single_prover() {
+        let evaluated_values = sample_evaluations();
+        let expected = Prover::init(&evaluated_values).prove();
+
+        for num_workers in [1, 2, 4, 16] {
+            let proof = DistributedProver::init(&evaluated_values, num_workers).prove();
+
+            assert_eq!(proof.initial_claimed_sum, expected.initial_claimed_sum);
+            assert_eq!(
+                proof.round_univariate_polynomials,
+                expected.round_univariate_polynomials
+            );
+        }
+    }
+
+    #[test]
+    fn test_distributed_sumcheck_roundtrip() {
+        let evaluated_values = sample_evaluations();
+        let mut prover = DistributedProver::init(&evaluated_values, 4);
+        let proof = prover.prove();
+
+        let mut verifier = Verifier::init();
+        assert!(
+            verifier.verify(proof),
+            "Distributed sumcheck proof verification failed"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "At least one worker is required")]
+    fn test_no_workers() {
+        let polynomial = MultilinearPoly::new(sample_evaluations()).into_shared();
+        let _ = DistributedProver::<Fq, LocalWorker<Fq>>::with_workers(polynomial, vec![]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of workers must not exceed the evaluation table size")]
+    fn test_more_workers_than_evaluations() {
+        let polynomial = MultilinearPoly::new(vec![Fq::from(1), Fq::from(2)]).into_shared();
+        let workers = (0..4u64)
+            .map(|i| LocalWorker {
+                polynomial: MultilinearPoly::new(vec![Fq::from(i)]),
+            })
+            .collect();
+        let _ = DistributedProver::with_workers(polynomial, workers);
+    }
+
+    #[test]
+    #[should_panic(expected = "DistributedProver can only prove once")]
+    fn test_prove_twice() {
+        let mut prover = DistributedProver::init(&sample_evaluations(), 4);
+        let _ = prover.prove();
+        let _ = prover.prove();
+    }
+
+    #[test]
+    #[should_panic(expected = "Worker evaluations must sum to the claimed sum")]
+    fn test_workers_not_partitioning_the_table() {
+        let evaluated_values = sample_evaluations();
+        let mut workers = partition_evaluations(&evaluated_values, 4);
+        workers[2].polynomial.evaluation[0] += Fq::from(1);
+        let polynomial = MultilinearPoly::new(evaluated_values).into_shared();
+
+        let _ = DistributedProver::with_workers(polynomial, workers).prove();
+    }
+
+    #[test]
+    #[should_panic(expected = "Worker evaluations must sum to the claimed sum")]
+    fn test_single_value_workers_not_partitioning_the_table() {
+        let evaluated_values = sample_evaluations();
+        let mut workers = partition_evaluations(&evaluated_values, 16);
+        workers[0].polynomial.evaluation[0] += Fq::from(1);
+        let polynomial = MultilinearPoly::new(evaluated_values).into_shared();
+
+        let _ = DistributedProver::with_workers(polynomial, workers).prove();
+    }
+
+    #[test]
+    #[should_panic(expected = "Number of workers must be a power of 2")]
+    fn test_invalid_worker_count() {
+        let evaluated_values = sample_evaluations();
+        let _ = DistributedProver::init(&evaluated_values, 3);
+    }
+}
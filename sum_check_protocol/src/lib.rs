@@ -1 +1,7 @@
+pub mod adversarial;
+pub mod committed_sumcheck;
+pub mod distributed;
+pub mod multivariate_sumcheck;
+pub mod report;
 pub mod sumcheck;
+pub mod virtual_sumcheck;
@@ -0,0 +1,207 @@
+use crate::sumcheck::field_element_to_bytes;
+use crate::virtual_sumcheck::evaluations_to_bytes;
+use ark_ff::PrimeField;
+use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
+use multilinear_polynomial::multivariate_polynomial::SparseMultivariatePoly;
+use polynomial_utils::barycentric::BarycentricWeights;
+
+/// A sumcheck proof for a `SparseMultivariatePoly`. Round i is sent as its evaluations at
+/// 0, 1, ..., deg_i, where deg_i is the polynomial's degree in variable i.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MultivariateSumcheckProof<F: PrimeField> {
+    pub initial_claimed_sum: F,
+    pub round_univariate_polynomials: Vec<Vec<F>>,
+}
+
+pub struct MultivariateProver<F: PrimeField> {
+    pub initial_polynomial: SparseMultivariatePoly<F>,
+    pub initial_claimed_sum: F,
+    pub transcript: Transcript,
+    pub round_univariate_polynomials: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> MultivariateProver<F> {
+    pub fn init(polynomial: SparseMultivariatePoly<F>) -> Self {
+        Self {
+            initial_claimed_sum: polynomial.sum_over_hypercube(),
+            initial_polynomial: polynomial,
+            transcript: Transcript::new(),
+            round_univariate_polynomials: Vec::new(),
+        }
+    }
+
+    /// Generates a Sumcheck proof, fixing variable 0 to the round challenge after each round.
+    pub fn prove(&mut self) -> MultivariateSumcheckProof<F> {
+        self.transcript
+            .append(&self.initial_polynomial.convert_to_bytes());
+        self.transcript
+            .append(&field_element_to_bytes(self.initial_claimed_sum));
+
+        let mut current_polynomial = self.initial_polynomial.clone();
+
+        for degree in round_degrees(&self.initial_polynomial) {
+            let round_evaluations: Vec<F> = (0..=degree as u64)
+                .map(|t| {
+                    current_polynomial
+                        .partial_evaluate(0, &F::from(t))
+                        .sum_over_hypercube()
+                })
+                .collect();
+            self.transcript
+                .append(&evaluations_to_bytes(&round_evaluations));
+            self.round_univariate_polynomials.push(round_evaluations);
+
+            let random_challenge: F = self.transcript.get_random_challenge();
+            current_polynomial = current_polynomial.partial_evaluate(0, &random_challenge);
+        }
+
+        MultivariateSumcheckProof {
+            initial_claimed_sum: self.initial_claimed_sum,
+            round_univariate_polynomials: self.round_univariate_polynomials.clone(),
+        }
+    }
+}
+
+pub struct MultivariateVerifier {
+    pub transcript: Transcript,
+}
+
+impl MultivariateVerifier {
+    pub fn init() -> Self {
+        Self {
+            transcript: Transcript::new(),
+        }
+    }
+
+    /// Verifies a Sumcheck proof for `polynomial`, checking each round polynomial against the
+    /// degree bound of its variable and the final claim against an evaluation of `polynomial`.
+    pub fn verify<F: PrimeField>(
+        &mut self,
+        polynomial: &SparseMultivariatePoly<F>,
+        proof: &MultivariateSumcheckProof<F>,
+    ) -> bool {
+        let degrees = round_degrees(polynomial);
+        if proof.round_univariate_polynomials.len() != degrees.len()
+            || proof
+                .round_univariate_polynomials
+                .iter()
+                .zip(degrees.iter())
+                .any(|(round, degree)| round.len() != degree + 1)
+        {
+            return false;
+        }
+
+        self.transcript.append(&polynomial.convert_to_bytes());
+        self.transcript
+            .append(&field_element_to_bytes(proof.initial_claimed_sum));
+
+        let mut current_claim_sum = proof.initial_claimed_sum;
+        let mut challenges = Vec::with_capacity(degrees.len());
+
+        for (round, degree) in proof.round_univariate_polynomials.iter().zip(degrees) {
+            if round[0] + round[1] != current_claim_sum {
+                return false;
+            }
+
+            self.transcript.append(&evaluations_to_bytes(round));
+            let challenge: F = self.transcript.get_random_challenge();
+            challenges.push(challenge);
+
+            current_claim_sum =
+                BarycentricWeights::for_consecutive_points(degree + 1).evaluate(round, challenge);
+        }
+
+        polynomial.evaluate(&challenges) == current_claim_sum
+    }
+}
+
+/// Rounds always carry at least g(0) and g(1), even for a variable the polynomial ignores.
+fn round_degrees<F: PrimeField>(polynomial: &SparseMultivariatePoly<F>) -> Vec<usize> {
+    polynomial
+        .degree_bounds()
+        .into_iter()
+        .map(|degree| degree.max(1))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+    use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+
+    /// Builds A(x,y)·A(y,z)·A(x,z) for the adjacency matrix A of an undirected graph on
+    /// 2^m vertices. Its sum over the hypercube is 6 times the number of triangles.
+    fn triangle_polynomial(m: usize, edges: &[(usize, usize)]) -> SparseMultivariatePoly<Fq> {
+        let mut adjacency = vec![Fq::from(0); 1 << (2 * m)];
+        for (u, v) in edges {
+            adjacency[u << m | v] = Fq::from(1);
+            adjacency[v << m | u] = Fq::from(1);
+        }
+
+        let a = SparseMultivariatePoly::from(MultilinearPoly::new(adjacency));
+        let x: Vec<usize> = (0..m).collect();
+        let y: Vec<usize> = (m..2 * m).collect();
+        let z: Vec<usize> = (2 * m..3 * m).collect();
+
+        let a_xy = a.embed(3 * m, &[x.clone(), y.clone()].concat());
+        let a_yz = a.embed(3 * m, &[y, z.clone()].concat());
+        let a_xz = a.embed(3 * m, &[x, z].concat());
+
+        &(&a_xy * &a_yz) * &a_xz
+    }
+
+    #[test]
+    fn test_counts_triangles() {
+        // Two triangles sharing the edge {1,2}: {0,1,2} and {1,2,3}.
+        let polynomial = triangle_polynomial(2, &[(0, 1), (1, 2), (0, 2), (1, 3), (2, 3)]);
+        assert_eq!(polynomial.degree_bounds(), vec![2; 6]);
+
+        let proof = MultivariateProver::init(polynomial.clone()).prove();
+        assert_eq!(proof.initial_claimed_sum, Fq::from(12));
+        assert!(proof
+            .round_univariate_polynomials
+            .iter()
+            .all(|round| round.len() == 3));
+
+        let mut verifier = MultivariateVerifier::init();
+        assert!(
+            verifier.verify(&polynomial, &proof),
+            "Multivariate sumcheck proof verification failed"
+        );
+    }
+
+    #[test]
+    fn test_rejects_wrong_triangle_count() {
+        let polynomial = triangle_polynomial(2, &[(0, 1), (1, 2), (0, 2)]);
+        let mut proof = MultivariateProver::init(polynomial.clone()).prove();
+        assert_eq!(proof.initial_claimed_sum, Fq::from(6));
+
+        proof.initial_claimed_sum += Fq::from(6);
+        proof.round_univariate_polynomials[0][0] += Fq::from(6);
+
+        let mut verifier = MultivariateVerifier::init();
+        assert!(!verifier.verify(&polynomial, &proof));
+    }
+
+    #[test]
+    fn test_rejects_round_above_degree_bound() {
+        // 2x²y + y + 3
+        let polynomial = SparseMultivariatePoly::new(
+            2,
+            vec![
+                (Fq::from(2), vec![2, 1]),
+                (Fq::from(1), vec![0, 1]),
+                (Fq::from(3), vec![0, 0]),
+            ],
+        );
+        let mut proof = MultivariateProver::init(polynomial.clone()).prove();
+        assert_eq!(proof.round_univariate_polynomials[0].len(), 3);
+        assert_eq!(proof.round_univariate_polynomials[1].len(), 2);
+
+        proof.round_univariate_polynomials[1].push(Fq::from(0));
+
+        let mut verifier = MultivariateVerifier::init();
+        assert!(!verifier.verify(&polynomial, &proof));
+    }
+}
@@ -0,0 +1,169 @@
+use crate::distributed::{DistributedProver, SumcheckWorker};
+use crate::sumcheck::{field_element_to_bytes, Prover, SumcheckProof};
+use ark_ff::PrimeField;
+
+/// Size, cost and soundness figures for a single sumcheck run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SumcheckReport {
+    /// Bytes needed to ship the proof, using the same encoding as the transcript.
+    pub proof_size_bytes: usize,
+    /// Field multiplications performed by the prover, if known.
+    pub field_multiplications: Option<usize>,
+    pub num_rounds: usize,
+    pub max_round_degree: usize,
+    /// Upper bound on the probability that a cheating prover is accepted: rounds·degree/|F|.
+    pub soundness_error: f64,
+}
+
+impl SumcheckReport {
+    /// Returns the soundness error as bits of security, i.e. -log2(soundness_error).
+    pub fn soundness_bits(&self) -> f64 {
+        -self.soundness_error.log2()
+    }
+}
+
+impl<F: PrimeField> SumcheckProof<F> {
+    /// Builds a report from the proof alone. The prover's cost is not recorded in the proof,
+    /// so `field_multiplications` is `None`; use `Prover::report` to obtain it.
+    pub fn report(&self) -> SumcheckReport {
+        let proof_size_bytes = self.initial_polynomial.convert_to_bytes().len()
+            + field_element_to_bytes(self.initial_claimed_sum).len()
+            + self
+                .round_univariate_polynomials
+                .iter()
+                .map(|polynomial| polynomial.convert_to_bytes().len())
+                .sum::<usize>();
+
+        // Round polynomials are sent in evaluation form, so d + 1 values describe degree d.
+        let max_round_degree = self
+            .round_univariate_polynomials
+            .iter()
+            .map(|polynomial| polynomial.evaluation.len().saturating_sub(1))
+            .max()
+            .unwrap_or(0);
+        let num_rounds = self.round_univariate_polynomials.len();
+
+        SumcheckReport {
+            proof_size_bytes,
+            field_multiplications: None,
+            num_rounds,
+            max_round_degree,
+            soundness_error: (num_rounds * max_round_degree) as f64 / field_size::<F>(),
+        }
+    }
+}
+
+impl<F: PrimeField> Prover<F> {
+    /// Builds a report for the last proof generated, including the prover's multiplication count.
+    pub fn report(&self) -> SumcheckReport {
+        let proof = SumcheckProof {
+            initial_polynomial: self.initial_polynomial.clone(),
+            initial_claimed_sum: self.initial_claimed_sum,
+            round_univariate_polynomials: self.round_univariate_polynomials.clone(),
+        };
+
+        SumcheckReport {
+            field_multiplications: Some(self.field_multiplications),
+            ..proof.report()
+        }
+    }
+}
+
+impl<F: PrimeField, W: SumcheckWorker<F>> DistributedProver<F, W> {
+    /// Builds a report for the proof generated, counting multiplications across all workers.
+    pub fn report(&self) -> SumcheckReport {
+        let proof = SumcheckProof {
+            initial_polynomial: self.initial_polynomial.clone(),
+            initial_claimed_sum: self.initial_claimed_sum,
+            round_univariate_polynomials: self.round_univariate_polynomials.clone(),
+        };
+
+        SumcheckReport {
+            field_multiplications: Some(self.field_multiplications),
+            ..proof.report()
+        }
+    }
+}
+
+/// Approximates |F| as a float from the limbs of the modulus.
+fn field_size<F: PrimeField>() -> f64 {
+    F::MODULUS
+        .as_ref()
+        .iter()
+        .rev()
+        .fold(0.0, |acc, limb| acc * 2f64.powi(64) + *limb as f64)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+
+    #[test]
+    fn test_prover_report() {
+        let evaluated_values = vec![
+            Fq::from(1),
+            Fq::from(2),
+            Fq::from(3),
+            Fq::from(4),
+            Fq::from(5),
+            Fq::from(6),
+            Fq::from(7),
+            Fq::from(8),
+        ];
+        let mut prover = Prover::init(&evaluated_values);
+        let proof = prover.prove();
+        let report = prover.report();
+
+        // 8 evaluations + claimed sum + 3 rounds of 2 evaluations, 32 bytes each.
+        assert_eq!(report.proof_size_bytes, (8 + 1 + 3 * 2) * 32);
+        // Folding 8 -> 4 -> 2 -> 1 costs one multiplication per pair.
+        assert_eq!(report.field_multiplications, Some(4 + 2 + 1));
+        assert_eq!(report.num_rounds, 3);
+        assert_eq!(report.max_round_degree, 1);
+
+        let proof_report = proof.report();
+        assert_eq!(proof_report.field_multiplications, None);
+        assert_eq!(proof_report.proof_size_bytes, report.proof_size_bytes);
+    }
+
+    #[test]
+    fn test_report_is_not_accumulated_across_proofs() {
+        let evaluated_values: Vec<Fq> = (1..=8u64).map(Fq::from).collect();
+        let mut prover = Prover::init(&evaluated_values);
+
+        let first_proof = prover.prove();
+        let first_report = prover.report();
+        let second_proof = prover.prove();
+
+        assert_eq!(first_proof, second_proof);
+        assert_eq!(prover.report(), first_report);
+    }
+
+    #[test]
+    fn test_distributed_report_matches_single_prover() {
+        let evaluated_values: Vec<Fq> = (1..=16u64).map(Fq::from).collect();
+        let mut prover = Prover::init(&evaluated_values);
+        prover.prove();
+
+        for num_workers in [1, 2, 4, 16] {
+            let mut distributed = DistributedProver::init(&evaluated_values, num_workers);
+            distributed.prove();
+
+            assert_eq!(distributed.report(), prover.report());
+        }
+    }
+
+    #[test]
+    fn test_soundness_bound() {
+        let evaluated_values = vec![Fq::from(0), Fq::from(0), Fq::from(3), Fq::from(8)];
+        let report = Prover::init(&evaluated_values).prove().report();
+
+        // 2 rounds of degree 1 over a 254-bit field: 2/|F| ≈ 2^-253.
+        let bits = report.soundness_bits();
+        assert!(
+            bits > 252.0 && bits < 254.0,
+            "unexpected soundness bits {bits}"
+        );
+    }
+}
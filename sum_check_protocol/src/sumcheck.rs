@@ -1,38 +1,52 @@
 use ark_ff::{BigInteger, PrimeField};
 use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
 use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+use multilinear_polynomial::multilinear_view::{MultilinearView, SharedMultilinear};
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 pub struct Prover<F: PrimeField> {
-    pub initial_polynomial: MultilinearPoly<F>,
+    pub initial_polynomial: SharedMultilinear<F>,
     pub initial_claimed_sum: F,
     pub transcript: Transcript,
     pub round_univariate_polynomials: Vec<MultilinearPoly<F>>,
+    pub field_multiplications: usize,
     pub is_initialized: bool,
 }
 
+#[derive(Clone, Debug, PartialEq)]
 pub struct SumcheckProof<F: PrimeField> {
-    pub initial_polynomial: MultilinearPoly<F>,
+    /// Shares the prover's table, so building a proof does not copy the evaluations.
+    pub initial_polynomial: SharedMultilinear<F>,
     pub initial_claimed_sum: F,
     pub round_univariate_polynomials: Vec<MultilinearPoly<F>>,
 }
 
 impl<F: PrimeField> Prover<F> {
-    /// Initializes a prover with a multilinear polynomial's evaluated values.
+    /// Initializes a prover with a multilinear polynomial's evaluated values, copying them
+    /// once into a shared table. Use `from_shared` to avoid the copy.
     /// Panics if the length of evaluated_values is not a power of 2.
-    pub fn init(multilinear_polynomial_evaluation: &Vec<F>) -> Self {
+    pub fn init(multilinear_polynomial_evaluation: &[F]) -> Self {
         assert!(
             multilinear_polynomial_evaluation.len().is_power_of_two(),
             "Polynomial evaluation length must be a power of 2"
         );
-        let polynomial = MultilinearPoly::new(multilinear_polynomial_evaluation.clone());
+
+        Self::from_shared(MultilinearView::new(Arc::from(
+            multilinear_polynomial_evaluation,
+        )))
+    }
+
+    /// Initializes a prover over a table that may also be used by other protocols.
+    pub fn from_shared(polynomial: SharedMultilinear<F>) -> Self {
         let transcript = Transcript::new();
 
         Prover {
+            initial_claimed_sum: polynomial.sum(),
             initial_polynomial: polynomial,
-            initial_claimed_sum: multilinear_polynomial_evaluation.iter().sum(),
             transcript,
             round_univariate_polynomials: Vec::new(),
+            field_multiplications: 0,
             is_initialized: true,
         }
     }
@@ -41,20 +55,26 @@ impl<F: PrimeField> Prover<F> {
     pub fn prove(&mut self) -> SumcheckProof<F> {
         assert!(self.is_initialized, "Can't prove without init");
 
+        // Each call produces a fresh proof, so start from a clean transcript and counters.
+        self.transcript = Transcript::new();
+        self.round_univariate_polynomials.clear();
+        self.field_multiplications = 0;
+
         self.transcript
             .append(&self.initial_polynomial.convert_to_bytes());
         self.transcript
             .append(&field_element_to_bytes(self.initial_claimed_sum));
 
-        // Wrap `current_polynomial` in a `MultilinearPoly`
-        let mut current_polynomial = MultilinearPoly {
-            evaluation: self.initial_polynomial.evaluation.clone(),
-            num_of_vars: self.initial_polynomial.num_of_vars,
-        };
+        // The first round reads the initial polynomial in place; only folded tables are owned.
+        let mut folded_polynomial: Option<MultilinearPoly<F>> = None;
 
         for _ in 0..self.initial_polynomial.number_of_variables() {
-            let univariate_polynomial_values =
-                split_polynomial_and_sum_each(&current_polynomial.evaluation);
+            let current_evaluations = folded_polynomial
+                .as_ref()
+                .map_or(self.initial_polynomial.evaluations(), |polynomial| {
+                    &polynomial.evaluation
+                });
+            let univariate_polynomial_values = split_polynomial_and_sum_each(current_evaluations);
             let univariate_polynomial = MultilinearPoly::new(univariate_polynomial_values.clone());
             let univariate_poly_in_bytes = univariate_polynomial.convert_to_bytes();
             self.round_univariate_polynomials
@@ -62,7 +82,15 @@ impl<F: PrimeField> Prover<F> {
             self.transcript.append(&univariate_poly_in_bytes);
 
             let random_challenge: F = self.transcript.get_random_challenge();
-            current_polynomial = current_polynomial.partial_evaluate(0, &random_challenge);
+            // Folding costs one multiplication per pair of evaluations.
+            self.field_multiplications += current_evaluations.len() / 2;
+            let next_polynomial = match &folded_polynomial {
+                Some(polynomial) => polynomial.partial_evaluate(0, &random_challenge),
+                None => self
+                    .initial_polynomial
+                    .partial_evaluate(0, &random_challenge),
+            };
+            folded_polynomial = Some(next_polynomial);
         }
 
         SumcheckProof {
@@ -75,9 +103,7 @@ impl<F: PrimeField> Prover<F> {
 
 /// Splits a polynomial's evaluated values into two halves and sums each half.
 /// Returns a univariate polynomial’s evaluations at 0 and 1.
-pub fn split_polynomial_and_sum_each<F: PrimeField>(
-    polynomial_evaluated_values: &Vec<F>,
-) -> Vec<F> {
+pub fn split_polynomial_and_sum_each<F: PrimeField>(polynomial_evaluated_values: &[F]) -> Vec<F> {
     let mut univariate_polynomial: Vec<F> = Vec::with_capacity(2);
 
     let mid = polynomial_evaluated_values.len() / 2;
@@ -116,44 +142,62 @@ impl<F: PrimeField> Verifier<F> {
         assert!(self.is_initialized, "Can't verify without init");
 
         if proof.round_univariate_polynomials.len()
-            != proof.initial_polynomial.number_of_variables() as usize
+            != proof.initial_polynomial.number_of_variables()
         {
             return false;
         }
 
-        let mut current_claim_sum = proof.initial_claimed_sum;
-
         self.transcript
             .append(&proof.initial_polynomial.convert_to_bytes());
         self.transcript
             .append(&field_element_to_bytes(proof.initial_claimed_sum));
 
-        let mut challenges: Vec<F> = Vec::with_capacity(proof.round_univariate_polynomials.len());
-
-        for i in 0..proof.round_univariate_polynomials.len() {
-            let eval_at_zero = vec![F::zero()];
-            let eval_at_one = vec![F::one()];
+        let Some((challenges, current_claim_sum)) = verify_rounds(
+            &mut self.transcript,
+            proof.initial_claimed_sum,
+            &proof.round_univariate_polynomials,
+        ) else {
+            return false;
+        };
 
-            if proof.round_univariate_polynomials[i].evaluate(eval_at_zero.clone())
-                + proof.round_univariate_polynomials[i].evaluate(eval_at_one.clone())
-                != current_claim_sum
-            {
-                return false;
-            }
+        let final_evaluation = proof.initial_polynomial.evaluate(&challenges);
+        final_evaluation == current_claim_sum
+    }
+}
 
-            self.transcript
-                .append(&proof.round_univariate_polynomials[i].convert_to_bytes());
+/// Replays the rounds of a linear sumcheck: each round polynomial must be sent as its
+/// evaluations at 0 and 1 and sum to the running claim. Returns the verifier's challenges and
+/// the final claim, or `None` at the first inconsistent round.
+pub(crate) fn verify_rounds<F: PrimeField>(
+    transcript: &mut Transcript,
+    initial_claimed_sum: F,
+    round_univariate_polynomials: &[MultilinearPoly<F>],
+) -> Option<(Vec<F>, F)> {
+    if round_univariate_polynomials
+        .iter()
+        .any(|polynomial| polynomial.number_of_variables() != 1)
+    {
+        return None;
+    }
 
-            let challenge: F = self.transcript.get_random_challenge();
-            challenges.push(challenge);
+    let mut current_claim_sum = initial_claimed_sum;
+    let mut challenges: Vec<F> = Vec::with_capacity(round_univariate_polynomials.len());
 
-            current_claim_sum =
-                proof.round_univariate_polynomials[i].evaluate(vec![challenge].clone());
+    for polynomial in round_univariate_polynomials {
+        if polynomial.evaluate(&[F::zero()]) + polynomial.evaluate(&[F::one()]) != current_claim_sum
+        {
+            return None;
         }
 
-        let final_evaluation = proof.initial_polynomial.evaluate(challenges);
-        final_evaluation == current_claim_sum
+        transcript.append(&polynomial.convert_to_bytes());
+
+        let challenge: F = transcript.get_random_challenge();
+        challenges.push(challenge);
+
+        current_claim_sum = polynomial.evaluate(&[challenge]);
     }
+
+    Some((challenges, current_claim_sum))
 }
 
 #[cfg(test)]
@@ -167,7 +211,7 @@ mod test {
         let prover = Prover::init(&evaluated_values);
 
         assert_eq!(prover.initial_claimed_sum, Fq::from(11));
-        assert_eq!(prover.initial_polynomial.evaluation, evaluated_values);
+        assert_eq!(prover.initial_polynomial.evaluations(), evaluated_values);
     }
 
     #[test]
@@ -180,6 +224,19 @@ mod test {
         assert!(verifier.verify(proof), "Sumcheck proof verification failed");
     }
 
+    #[test]
+    fn test_proof_shares_the_prover_table() {
+        let shared = MultilinearPoly::new(vec![Fq::from(2), Fq::from(4), Fq::from(1), Fq::from(9)])
+            .into_shared();
+        let mut prover = Prover::from_shared(shared.clone());
+        let proof = prover.prove();
+
+        assert!(Arc::ptr_eq(
+            &proof.initial_polynomial.into_inner(),
+            &shared.into_inner()
+        ));
+    }
+
     #[test]
     #[should_panic(expected = "Polynomial evaluation length must be a power of 2")]
     fn test_invalid_length() {
@@ -0,0 +1,180 @@
+use crate::sumcheck::field_element_to_bytes;
+use ark_ff::PrimeField;
+use fiat_shamir_transcript::fiat_shamir_transcript::Transcript;
+use multilinear_polynomial::virtual_polynomial::VirtualPolynomial;
+use polynomial_utils::barycentric::BarycentricWeights;
+
+/// A sumcheck proof for a `VirtualPolynomial`. Round polynomials may have degree above 1,
+/// so each is sent as its evaluations at 0, 1, ..., degree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VirtualSumcheckProof<F: PrimeField> {
+    pub initial_claimed_sum: F,
+    pub round_univariate_polynomials: Vec<Vec<F>>,
+}
+
+pub struct VirtualProver<F: PrimeField> {
+    pub initial_polynomial: VirtualPolynomial<F>,
+    pub initial_claimed_sum: F,
+    pub transcript: Transcript,
+    pub round_univariate_polynomials: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> VirtualProver<F> {
+    pub fn init(polynomial: VirtualPolynomial<F>) -> Self {
+        Self {
+            initial_claimed_sum: polynomial.sum_over_hypercube(),
+            initial_polynomial: polynomial,
+            transcript: Transcript::new(),
+            round_univariate_polynomials: Vec::new(),
+        }
+    }
+
+    /// Generates a Sumcheck proof, folding variable 0 of every factor after each round.
+    pub fn prove(&mut self) -> VirtualSumcheckProof<F> {
+        // Each call produces a fresh proof, so start from a clean transcript.
+        self.transcript = Transcript::new();
+        self.round_univariate_polynomials.clear();
+
+        self.transcript
+            .append(&self.initial_polynomial.convert_to_bytes());
+        self.transcript
+            .append(&field_element_to_bytes(self.initial_claimed_sum));
+
+        let mut current_polynomial = self.initial_polynomial.clone();
+
+        for _ in 0..self.initial_polynomial.number_of_variables() {
+            let round_evaluations = current_polynomial.round_evaluations();
+            self.transcript
+                .append(&evaluations_to_bytes(&round_evaluations));
+            self.round_univariate_polynomials.push(round_evaluations);
+
+            let random_challenge: F = self.transcript.get_random_challenge();
+            current_polynomial = current_polynomial.partial_evaluate(&random_challenge);
+        }
+
+        VirtualSumcheckProof {
+            initial_claimed_sum: self.initial_claimed_sum,
+            round_univariate_polynomials: self.round_univariate_polynomials.clone(),
+        }
+    }
+}
+
+pub struct VirtualVerifier {
+    pub transcript: Transcript,
+}
+
+impl VirtualVerifier {
+    pub fn init() -> Self {
+        Self {
+            transcript: Transcript::new(),
+        }
+    }
+
+    /// Verifies a Sumcheck proof for `polynomial`, checking every round polynomial has the
+    /// expected degree and that the final claim matches an evaluation of `polynomial`.
+    pub fn verify<F: PrimeField>(
+        &mut self,
+        polynomial: &VirtualPolynomial<F>,
+        proof: &VirtualSumcheckProof<F>,
+    ) -> bool {
+        if proof.round_univariate_polynomials.len() != polynomial.number_of_variables() {
+            return false;
+        }
+
+        // Rounds always carry at least g(0) and g(1), even for a constant polynomial.
+        let degree = polynomial.max_degree().max(1);
+        if proof
+            .round_univariate_polynomials
+            .iter()
+            .any(|round| round.len() != degree + 1)
+        {
+            return false;
+        }
+
+        self.transcript.append(&polynomial.convert_to_bytes());
+        self.transcript
+            .append(&field_element_to_bytes(proof.initial_claimed_sum));
+
+        let weights = BarycentricWeights::for_consecutive_points(degree + 1);
+        let mut current_claim_sum = proof.initial_claimed_sum;
+        let mut challenges = Vec::with_capacity(proof.round_univariate_polynomials.len());
+
+        for round in &proof.round_univariate_polynomials {
+            if round[0] + round[1] != current_claim_sum {
+                return false;
+            }
+
+            self.transcript.append(&evaluations_to_bytes(round));
+            let challenge: F = self.transcript.get_random_challenge();
+            challenges.push(challenge);
+
+            current_claim_sum = weights.evaluate(round, challenge);
+        }
+
+        polynomial.evaluate(&challenges) == current_claim_sum
+    }
+}
+
+pub(crate) fn evaluations_to_bytes<F: PrimeField>(evaluations: &[F]) -> Vec<u8> {
+    evaluations
+        .iter()
+        .flat_map(|value| field_element_to_bytes(*value))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ark_bn254::Fq;
+    use multilinear_polynomial::multilinear_polynomial_evaluation::MultilinearPoly;
+
+    fn test_setup() -> VirtualPolynomial<Fq> {
+        let a = MultilinearPoly::new((1..=8u64).map(Fq::from).collect()).into_shared();
+        let b = MultilinearPoly::new((0..8u64).map(|i| Fq::from(i * i)).collect()).into_shared();
+        let c = MultilinearPoly::new((0..8u64).map(|i| Fq::from(i % 3)).collect()).into_shared();
+
+        // a·b·c + 4·a·a + 7·c
+        let mut polynomial = VirtualPolynomial::new(3);
+        polynomial.add_product(Fq::from(1), vec![a.clone(), b, c.clone()]);
+        polynomial.add_product(Fq::from(4), vec![a.clone(), a]);
+        polynomial.add_product(Fq::from(7), vec![c]);
+
+        polynomial
+    }
+
+    #[test]
+    fn test_virtual_sumcheck_roundtrip() {
+        let polynomial = test_setup();
+        let proof = VirtualProver::init(polynomial.clone()).prove();
+
+        assert_eq!(proof.round_univariate_polynomials[0].len(), 4);
+
+        let mut verifier = VirtualVerifier::init();
+        assert!(
+            verifier.verify(&polynomial, &proof),
+            "Virtual sumcheck proof verification failed"
+        );
+    }
+
+    #[test]
+    fn test_virtual_prover_can_prove_twice() {
+        let polynomial = test_setup();
+        let mut prover = VirtualProver::init(polynomial.clone());
+        let first = prover.prove();
+        let second = prover.prove();
+
+        assert_eq!(first, second);
+        assert!(VirtualVerifier::init().verify(&polynomial, &second));
+    }
+
+    #[test]
+    fn test_virtual_sumcheck_rejects_wrong_sum() {
+        let polynomial = test_setup();
+        let mut proof = VirtualProver::init(polynomial.clone()).prove();
+        proof.initial_claimed_sum += Fq::from(1);
+        proof.round_univariate_polynomials[0][1] += Fq::from(1);
+
+        let mut verifier = VirtualVerifier::init();
+        assert!(!verifier.verify(&polynomial, &proof));
+    }
+}
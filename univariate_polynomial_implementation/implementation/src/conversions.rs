@@ -0,0 +1,155 @@
+use crate::dense_impl::DenseUnivariatePoly;
+use crate::sparse_impl::SparseUnivariatePoly;
+use ark_ff::PrimeField;
+use polynomial_utils::polynomial::DenseUnivariatePoly as UtilsDenseUnivariatePoly;
+use polynomial_utils::univariate::UnivariatePolynomial;
+
+// Every conversion goes through the trimmed coefficient vector, so no term is lost.
+// The zero polynomial becomes the dense polynomial [0].
+fn convert<F: PrimeField, A: UnivariatePolynomial<F>, B: UnivariatePolynomial<F>>(poly: &A) -> B {
+    let coefficients = poly.coefficients();
+
+    if coefficients.is_empty() {
+        B::from_coefficients(vec![F::zero()])
+    } else {
+        B::from_coefficients(coefficients)
+    }
+}
+
+impl<F: PrimeField> From<&DenseUnivariatePoly<F>> for SparseUnivariatePoly<F> {
+    fn from(poly: &DenseUnivariatePoly<F>) -> Self {
+        convert(poly)
+    }
+}
+
+impl<F: PrimeField> From<DenseUnivariatePoly<F>> for SparseUnivariatePoly<F> {
+    fn from(poly: DenseUnivariatePoly<F>) -> Self {
+        convert(&poly)
+    }
+}
+
+impl<F: PrimeField> From<&SparseUnivariatePoly<F>> for DenseUnivariatePoly<F> {
+    fn from(poly: &SparseUnivariatePoly<F>) -> Self {
+        convert(poly)
+    }
+}
+
+impl<F: PrimeField> From<SparseUnivariatePoly<F>> for DenseUnivariatePoly<F> {
+    fn from(poly: SparseUnivariatePoly<F>) -> Self {
+        convert(&poly)
+    }
+}
+
+impl<F: PrimeField> From<UtilsDenseUnivariatePoly<F>> for DenseUnivariatePoly<F> {
+    fn from(poly: UtilsDenseUnivariatePoly<F>) -> Self {
+        convert(&poly)
+    }
+}
+
+impl<F: PrimeField> From<DenseUnivariatePoly<F>> for UtilsDenseUnivariatePoly<F> {
+    fn from(poly: DenseUnivariatePoly<F>) -> Self {
+        convert(&poly)
+    }
+}
+
+impl<F: PrimeField> From<UtilsDenseUnivariatePoly<F>> for SparseUnivariatePoly<F> {
+    fn from(poly: UtilsDenseUnivariatePoly<F>) -> Self {
+        convert(&poly)
+    }
+}
+
+impl<F: PrimeField> From<SparseUnivariatePoly<F>> for UtilsDenseUnivariatePoly<F> {
+    fn from(poly: SparseUnivariatePoly<F>) -> Self {
+        convert(&poly)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+
+    // Written against the trait only, so it runs unchanged on every representation
+    fn evaluate_product<F: PrimeField, P: UnivariatePolynomial<F>>(a: &P, b: &P, x: F) -> F {
+        a.mul(b).evaluate(x)
+    }
+
+    fn test_coefficients() -> Vec<Fr> {
+        vec![
+            Fr::from(7),
+            Fr::from(0),
+            Fr::from(2),
+            Fr::from(0),
+            Fr::from(9),
+        ]
+    }
+
+    #[test]
+    fn test_dense_sparse_roundtrip() {
+        let dense = DenseUnivariatePoly::new(test_coefficients());
+        let sparse = SparseUnivariatePoly::from(&dense);
+
+        assert_eq!(sparse.degree(), Some(4));
+        assert_eq!(DenseUnivariatePoly::from(&sparse), dense);
+
+        let utils = UtilsDenseUnivariatePoly::from(sparse.clone());
+        assert_eq!(utils.coefficients, test_coefficients());
+        assert_eq!(SparseUnivariatePoly::from(utils.clone()), sparse);
+        assert_eq!(DenseUnivariatePoly::from(utils), dense);
+    }
+
+    #[test]
+    fn test_zero_polynomial_conversion() {
+        let sparse = SparseUnivariatePoly::<Fr>::new();
+        let dense = DenseUnivariatePoly::from(&sparse);
+
+        assert_eq!(dense, DenseUnivariatePoly::new(vec![Fr::from(0)]));
+        assert_eq!(SparseUnivariatePoly::from(dense.clone()), sparse);
+
+        // The utils type stores zero as [0] too, and both report it as having no degree
+        let utils = UtilsDenseUnivariatePoly::from(DenseUnivariatePoly::new(vec![Fr::from(0); 3]));
+        assert_eq!(utils.coefficients, vec![Fr::from(0)]);
+        assert_eq!(utils.degree(), None);
+        assert_eq!(dense.degree(), None);
+        assert_eq!(DenseUnivariatePoly::from(utils.clone()), dense);
+        assert_eq!(UtilsDenseUnivariatePoly::from(sparse), utils);
+    }
+
+    #[test]
+    fn test_representations_agree() {
+        let a = test_coefficients();
+        let b = vec![Fr::from(3), Fr::from(1)];
+        let x = Fr::from(5);
+
+        let dense = evaluate_product(
+            &DenseUnivariatePoly::new(a.clone()),
+            &DenseUnivariatePoly::new(b.clone()),
+            x,
+        );
+        let sparse = evaluate_product(
+            &SparseUnivariatePoly::from_coefficients(a.clone()),
+            &SparseUnivariatePoly::from_coefficients(b.clone()),
+            x,
+        );
+        let utils = evaluate_product(
+            &UtilsDenseUnivariatePoly::new(a),
+            &UtilsDenseUnivariatePoly::new(b),
+            x,
+        );
+
+        assert_eq!(dense, sparse);
+        assert_eq!(dense, utils);
+    }
+
+    #[test]
+    fn test_sparse_arithmetic_accumulates_terms() {
+        // (x + 1)² = x² + 2x + 1, and (x + 1) - (x + 1) cancels completely
+        let p = SparseUnivariatePoly::from_coefficients(vec![Fr::from(1), Fr::from(1)]);
+
+        assert_eq!(
+            p.mul(&p).coefficients(),
+            vec![Fr::from(1), Fr::from(2), Fr::from(1)]
+        );
+        assert_eq!(p.add(&p.mul_scalar(-Fr::from(1))).degree(), None);
+    }
+}
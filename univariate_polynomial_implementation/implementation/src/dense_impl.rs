@@ -1,6 +1,10 @@
 use ark_ff::PrimeField;
+use polynomial_utils::division;
+use polynomial_utils::interpolation;
+use polynomial_utils::operations;
+use polynomial_utils::univariate::from_trimmed;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DenseUnivariatePoly<F: PrimeField> {
     coefficients: Vec<F>,
 }
@@ -12,78 +16,30 @@ impl<F: PrimeField> DenseUnivariatePoly<F> {
         }
     }
 
-    pub fn degree(&self) -> u32 {
-        self.coefficients.len() as u32 - 1
+    /// Shares the interpolation of `polynomial_utils`, which switches to a subproduct tree
+    /// for large inputs.
+    pub fn lagrange_interpolate(x_values: &[F], y_values: &[F]) -> DenseUnivariatePoly<F> {
+        DenseUnivariatePoly::new(
+            interpolation::lagrange_interpolate(x_values, y_values).coefficients,
+        )
     }
 
-    pub fn evaluate(&self, value: F) -> F {
-        let mut result = F::zero();
-
-        for (index, coeff) in self.coefficients.iter().enumerate() {
-            result += *coeff * value.pow([index as u64]);
-        }
-
-        result
+    /// Returns the quotient by (x − a) and the remainder, which equals f(a).
+    pub fn div_by_linear(&self, a: F) -> (Self, F) {
+        let (quotient, remainder) = division::divide_by_linear(&self.coefficients, a);
+        (from_trimmed(quotient), remainder)
     }
 
-    pub fn lagrange_interpolate(x_values: &Vec<F>, y_values: &Vec<F>) -> DenseUnivariatePoly<F> {
-        let mut final_interpolated_polynomial = vec![F::zero()];
-
-        for (index, x_value) in x_values.iter().enumerate() {
-            let current_polynomial = lagrange_basis(y_values[index], *x_value, x_values.clone());
-
-            final_interpolated_polynomial =
-                add_polynomials(final_interpolated_polynomial, current_polynomial)
-        }
-
-        DenseUnivariatePoly {
-            coefficients: final_interpolated_polynomial,
-        }
+    /// Returns (quotient, remainder) of division by x^n − c.
+    pub fn div_by_vanishing(&self, n: usize, c: F) -> (Self, Self) {
+        let (quotient, remainder) = division::divide_by_vanishing(&self.coefficients, n, c);
+        (from_trimmed(quotient), from_trimmed(remainder))
     }
 }
 
-fn lagrange_basis<F: PrimeField>(
-    y_point: F,
-    focus_x_point: F,
-    interpolating_set: Vec<F>,
-) -> Vec<F> {
-    let mut numerator = vec![F::one()];
-
-    for x in interpolating_set.iter() {
-        if *x != focus_x_point {
-            numerator = multiply_polynomials(numerator, vec![-*x, F::one()]);
-        }
-    }
-
-    let univariate_poly: DenseUnivariatePoly<F> = DenseUnivariatePoly::new(numerator.clone());
-
-    let denominator = univariate_poly.evaluate(focus_x_point);
-
-    let interpolated_polynomial = scalar_mul(y_point / denominator, numerator);
-
-    interpolated_polynomial
-}
-
-fn scalar_mul<F: PrimeField>(scalar: F, polynomial: Vec<F>) -> Vec<F> {
-    let mut result_polynomial: Vec<F> = Vec::new();
-
-    for coeff in polynomial.iter() {
-        result_polynomial.push(scalar * coeff);
-    }
-
-    result_polynomial
-}
-
+/// Shares the schoolbook/NTT switch-over of `polynomial_utils::operations`.
 pub fn multiply_polynomials<F: PrimeField>(left: Vec<F>, right: Vec<F>) -> Vec<F> {
-    let mut polynomial_product = vec![F::zero(); (left.len() + right.len()) - 1];
-
-    for (left_index, left_coeff) in left.iter().enumerate() {
-        for (right_index, right_coeff) in right.iter().enumerate() {
-            polynomial_product[left_index + right_index] += *left_coeff * *right_coeff;
-        }
-    }
-
-    polynomial_product
+    operations::multiply_polynomials(left, right)
 }
 
 pub fn add_polynomials<F: PrimeField>(left: Vec<F>, right: Vec<F>) -> Vec<F> {
@@ -106,22 +62,23 @@ pub fn add_polynomials<F: PrimeField>(left: Vec<F>, right: Vec<F>) -> Vec<F> {
     summed_polynomial
 }
 
+polynomial_utils::impl_dense_univariate_polynomial!(DenseUnivariatePoly);
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ark_bn254::Fq;
+    use ark_bn254::{Fq, Fr};
+    use polynomial_utils::univariate::UnivariatePolynomial;
 
     fn test_setup() -> DenseUnivariatePoly<Fq> {
         let set_of_points = vec![Fq::from(7), Fq::from(5), Fq::from(2), Fq::from(1)];
-        let polynomial = DenseUnivariatePoly::new(set_of_points.clone());
-
-        polynomial
+        DenseUnivariatePoly::new(set_of_points)
     }
 
     #[test]
     fn test_degree() {
         let polynomial = test_setup();
-        assert_eq!(polynomial.degree(), 3);
+        assert_eq!(polynomial.degree(), Some(3));
     }
 
     #[test]
@@ -154,6 +111,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_multiply_large_polynomials() {
+        let p1: Vec<Fr> = (0..200u64).map(Fr::from).collect();
+        let p2: Vec<Fr> = (0..150u64).map(|i| Fr::from(i * i + 1)).collect();
+        let product = multiply_polynomials(p1.clone(), p2.clone());
+
+        let x = Fr::from(3);
+        assert_eq!(product.len(), 349);
+        assert_eq!(
+            DenseUnivariatePoly::new(product).evaluate(x),
+            DenseUnivariatePoly::new(p1).evaluate(x) * DenseUnivariatePoly::new(p2).evaluate(x)
+        );
+    }
+
+    #[test]
+    fn test_div_rem() {
+        // (x² + 3x + 2) = (x + 1)(x + 2)
+        let poly = DenseUnivariatePoly::new(vec![Fq::from(2), Fq::from(3), Fq::from(1)]);
+        let (quotient, remainder) =
+            poly.div_rem(&DenseUnivariatePoly::new(vec![Fq::from(1), Fq::from(1)]));
+
+        assert_eq!(quotient.coefficients, vec![Fq::from(2), Fq::from(1)]);
+        assert_eq!(remainder.coefficients, vec![Fq::from(0)]);
+
+        let (quotient, remainder) = poly.div_by_linear(Fq::from(2));
+        assert_eq!(quotient.coefficients, vec![Fq::from(5), Fq::from(1)]);
+        assert_eq!(remainder, Fq::from(12));
+
+        // x² + 3x + 2 = 1·(x² − 4) + 3x + 6
+        let (quotient, remainder) = poly.div_by_vanishing(2, Fq::from(4));
+        assert_eq!(quotient.coefficients, vec![Fq::from(1)]);
+        assert_eq!(remainder.coefficients, vec![Fq::from(6), Fq::from(3)]);
+    }
+
     #[test]
     fn test_lagrange_interpolate() {
         let x_values = vec![Fq::from(1), Fq::from(2), Fq::from(3)];
@@ -1,2 +1,3 @@
+pub mod conversions;
 pub mod dense_impl;
 pub mod sparse_impl;
@@ -1,8 +1,9 @@
 use ark_ff::PrimeField;
-use std::collections::HashMap;
+use polynomial_utils::univariate::UnivariatePolynomial;
+use std::collections::{BTreeMap, HashMap};
 
 // Sparse Representation: Only stores the non-zero terms
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub struct SparseUnivariatePoly<F: PrimeField> {
     coefficients: HashMap<usize, F>, // degree -> coefficient
 }
@@ -29,7 +30,7 @@ impl<F: PrimeField> SparseUnivariatePoly<F> {
     pub fn evaluate(&self, value: F) -> F {
         let mut result = F::zero();
         for (degree, coeff) in &self.coefficients {
-            result += value.pow(&[*degree as u64]) * *coeff;
+            result += value.pow([*degree as u64]) * *coeff;
         }
         result
     }
@@ -61,6 +62,10 @@ impl<F: PrimeField> SparseUnivariatePoly<F> {
     }
 
     pub fn mul_scalar(&self, scalar: F) -> Self {
+        if scalar.is_zero() {
+            return Self::new();
+        }
+
         let mut result = self.clone();
         for coeff in result.coefficients.values_mut() {
             *coeff *= scalar;
@@ -73,7 +78,7 @@ impl<F: PrimeField> SparseUnivariatePoly<F> {
         let mut result = Self::new();
         for (&d1, &c1) in &self.coefficients {
             for (&d2, &c2) in &other.coefficients {
-                result.insert_term(d1 + d2, c1 * c2);
+                result.add_to_term(d1 + d2, c1 * c2);
             }
         }
         result
@@ -83,12 +88,56 @@ impl<F: PrimeField> SparseUnivariatePoly<F> {
     pub fn add(&self, other: &Self) -> Self {
         let mut result = self.clone();
         for (&d, &c) in &other.coefficients {
-            let new_coeff = result.coefficients.entry(d).or_insert(F::zero());
-            *new_coeff += c;
+            result.add_to_term(d, c);
         }
         result
     }
 
+    // Accumulates into a term, dropping it if it cancels to zero
+    fn add_to_term(&mut self, degree: usize, coeff: F) {
+        let sum = self.coefficients.get(&degree).copied().unwrap_or(F::zero()) + coeff;
+        self.insert_term(degree, sum);
+    }
+
+    /// Long division, returning (quotient, remainder). Each step cancels the leading term of
+    /// the remainder and only touches the divisor's non-zero terms, so sparse divisors such
+    /// as x^n - c stay cheap. Panics on a zero divisor.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        let divisor_degree = divisor.degree().expect("Division by zero polynomial");
+        let leading_inv = divisor.coefficients[&divisor_degree]
+            .inverse()
+            .expect("leading coefficient is non-zero");
+
+        let mut remainder: BTreeMap<usize, F> =
+            self.coefficients.iter().map(|(&d, &c)| (d, c)).collect();
+        let mut quotient = Self::new();
+
+        while let Some((&degree, &coeff)) = remainder.last_key_value() {
+            if degree < divisor_degree {
+                break;
+            }
+
+            let shift = degree - divisor_degree;
+            let factor = coeff * leading_inv;
+            quotient.insert_term(shift, factor);
+
+            for (&d, &c) in &divisor.coefficients {
+                let entry = remainder.entry(d + shift).or_insert(F::zero());
+                *entry -= factor * c;
+                if entry.is_zero() {
+                    remainder.remove(&(d + shift));
+                }
+            }
+        }
+
+        let mut remainder_poly = Self::new();
+        for (degree, coeff) in remainder {
+            remainder_poly.insert_term(degree, coeff);
+        }
+
+        (quotient, remainder_poly)
+    }
+
     /// ✅ **Interpolate Using Lagrange Basis**
     pub fn interpolate(points: &[(F, F)]) -> Self {
         let mut poly = Self::new();
@@ -100,6 +149,57 @@ impl<F: PrimeField> SparseUnivariatePoly<F> {
     }
 }
 
+impl<F: PrimeField> UnivariatePolynomial<F> for SparseUnivariatePoly<F> {
+    fn from_coefficients(coefficients: Vec<F>) -> Self {
+        let mut poly = Self::new();
+        for (degree, coeff) in coefficients.into_iter().enumerate() {
+            poly.insert_term(degree, coeff);
+        }
+        poly
+    }
+
+    fn coefficients(&self) -> Vec<F> {
+        let mut coefficients = vec![F::zero(); self.degree().map_or(0, |degree| degree + 1)];
+        for (&degree, &coeff) in &self.coefficients {
+            coefficients[degree] = coeff;
+        }
+        coefficients
+    }
+
+    fn degree(&self) -> Option<usize> {
+        SparseUnivariatePoly::degree(self)
+    }
+
+    fn evaluate(&self, value: F) -> F {
+        SparseUnivariatePoly::evaluate(self, value)
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        SparseUnivariatePoly::add(self, other)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        SparseUnivariatePoly::mul(self, other)
+    }
+
+    fn scalar_mul(&self, scalar: F) -> Self {
+        self.mul_scalar(scalar)
+    }
+
+    fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        SparseUnivariatePoly::div_rem(self, divisor)
+    }
+
+    fn interpolate(x_values: &[F], y_values: &[F]) -> Self {
+        let points: Vec<(F, F)> = x_values
+            .iter()
+            .copied()
+            .zip(y_values.iter().copied())
+            .collect();
+        SparseUnivariatePoly::interpolate(&points)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -168,6 +268,32 @@ mod tests {
         assert_eq!(result.coefficients.get(&1), Some(&Fr::from(5u32)));
     }
 
+    #[test]
+    fn test_div_rem() {
+        // 2x^10 + 5x^3 + 1 = (2x^6 + 6x^2)(x^4 - 3) + 5x^3 + 18x^2 + 1
+        let mut dividend = SparseUnivariatePoly::new();
+        dividend.insert_term(10, Fr::from(2u32));
+        dividend.insert_term(3, Fr::from(5u32));
+        dividend.insert_term(0, Fr::from(1u32));
+
+        let mut divisor = SparseUnivariatePoly::new();
+        divisor.insert_term(4, Fr::from(1u32));
+        divisor.insert_term(0, -Fr::from(3u32));
+
+        let (quotient, remainder) = dividend.div_rem(&divisor);
+        assert_eq!(quotient.coefficients.len(), 2);
+        assert_eq!(quotient.coefficients.get(&6), Some(&Fr::from(2u32)));
+        assert_eq!(quotient.coefficients.get(&2), Some(&Fr::from(6u32)));
+        assert_eq!(remainder.coefficients.get(&3), Some(&Fr::from(5u32)));
+        assert_eq!(remainder.coefficients.get(&2), Some(&Fr::from(18u32)));
+        assert_eq!(remainder.coefficients.get(&0), Some(&Fr::from(1u32)));
+
+        // Exact division leaves no remainder
+        let (exact, remainder) = quotient.mul(&divisor).div_rem(&divisor);
+        assert_eq!(exact, quotient);
+        assert_eq!(remainder.degree(), None);
+    }
+
     #[test]
     fn test_lagrange_basis() {
         let points = vec![